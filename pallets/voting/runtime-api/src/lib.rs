@@ -0,0 +1,18 @@
+//! Runtime API definition for the voting pallet.
+//!
+//! Allows clients to read the live standing of a proposal without submitting an extrinsic.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_voting::{ProposalId, ProposalStateInfo};
+
+sp_api::decl_runtime_apis! {
+	pub trait VotingApi<BlockNumber>
+	where
+		BlockNumber: Codec,
+	{
+		/// Returns the current tallies of a proposal and the status it would get if it was
+		/// finished at this moment. `None` if the proposal does not exist.
+		fn proposal_state(id: ProposalId) -> Option<ProposalStateInfo<BlockNumber>>;
+	}
+}
@@ -16,12 +16,17 @@ const SEED: u32 = 0;
 pub mod benchmarks {
 	
 	use sp_core::H256;
-	use frame_support::traits::Currency;
+	use sp_std::vec::Vec;
+	use frame_support::{traits::Currency, BoundedVec};
 	use super::*;
 
+	fn admin_origin<T: Config>() -> T::RuntimeOrigin {
+		T::AdminOrigin::try_successful_origin().expect("AdminOrigin has no successful origin")
+	}
+
 	fn get_registered_proposer<T: Config>() -> T::AccountId {
 		let proposer: T::AccountId = account("proposer", 0, SEED);
-		let _ = Voting::<T>::register_voter(RawOrigin::Root.into(), proposer.clone());
+		let _ = Voting::<T>::register_voter(admin_origin::<T>(), proposer.clone());
 	
 		proposer
 	}
@@ -31,22 +36,91 @@ pub mod benchmarks {
 	fn register_voter() {
 		//setup
 		let voter: T::AccountId = account("recipient", 0, SEED);
+		let origin = admin_origin::<T>();
 		
 		#[extrinsic_call]
-		_(RawOrigin::Root, voter.clone());
+		_(origin as T::RuntimeOrigin, voter.clone());
 		
 		//verify
 		assert!(Voting::<T>::is_registered(&voter));
 	}
 	
+	#[benchmark]
+	fn register_voter_with_deposit() {
+		//setup
+		let voter: T::AccountId = account("recipient", 0, SEED);
+		let _ = T::Currency::make_free_balance_be(&voter, T::MembershipDeposit::get() * 2u32.into());
+		let origin = admin_origin::<T>();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, voter.clone());
+
+		//verify
+		assert!(MembershipDeposits::<T>::contains_key(&voter));
+	}
+
+	#[benchmark]
+	fn unregister_voter(p: Linear<0, { T::MaxProposals::get() }>) {
+		//setup: the voter delegated and the votes of the delegatee are looked up on p proposals
+		let voter: T::AccountId = account("recipient", 0, SEED);
+		let _ = T::Currency::make_free_balance_be(&voter, T::MembershipDeposit::get() * 2u32.into());
+		let _ = Voting::<T>::register_voter_with_deposit(admin_origin::<T>(), voter.clone());
+		let delegatee = get_registered_proposer::<T>();
+		let _ = Voting::<T>::delegate(RawOrigin::Signed(voter.clone()).into(), delegatee.clone());
+		let _ = Voting::<T>::set_delegation_cap(admin_origin::<T>(), voter.clone(), Some(1));
+		for i in 1..=p {
+			Proposals::<T>::insert(i, Proposal::<T>::new(i, delegatee.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+			let _ = ActiveProposalIds::<T>::try_append((100_000u32.into(), i));
+		}
+		let origin = admin_origin::<T>();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, voter.clone());
+
+		//verify
+		assert!(!Voting::<T>::is_registered(&voter));
+		assert!(!Delegations::<T>::contains_key(&voter));
+	}
+
+	#[benchmark]
+	fn force_register_voter() {
+		//setup
+		let voter: T::AccountId = account("recipient", 0, SEED);
+		AmountVoters::<T>::put(Voting::<T>::max_voters());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, voter.clone());
+
+		//verify
+		assert!(Voting::<T>::is_registered(&voter));
+	}
+
+	#[benchmark]
+	fn register_voters(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		//setup
+		let voters: Vec<T::AccountId> = (0..n).map(|i| account("recipient", i, SEED)).collect();
+		let voters: BoundedVec<T::AccountId, T::MaxBatchSize> = voters.try_into().unwrap();
+		let origin = admin_origin::<T>();
+
+		#[extrinsic_call]
+		batch_register_voters(origin as T::RuntimeOrigin, voters.clone());
+
+		//verify
+		assert!(voters.iter().all(|voter| Voting::<T>::is_registered(voter)));
+	}
+
 	#[benchmark]
 	fn make_proposal() {
-		let description = H256([0;32]);
+		let description = H256([1;32]);
 		let time_period: u32 = 100000;
 		let proposer = get_registered_proposer::<T>();
+		let _ = T::Currency::make_free_balance_be(
+			&proposer,
+			T::ProposalDeposit::get() + T::Currency::minimum_balance()
+		);
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(proposer), description, time_period.into());
+		_(RawOrigin::Signed(proposer), description, time_period.into(), None, None, None, Vec::new(), None);
 
 		//verify
 		let counter = Voting::<T>::get_proposal_counter();
@@ -54,20 +128,37 @@ pub mod benchmarks {
 	}
 
 	#[benchmark]
-	fn increase_proposal_time(x: Linear<1, 10_000>){
+	fn transfer_proposal_ownership() {
 		//setup
 		let proposer = get_registered_proposer::<T>();
-		for i in 0..x {
-			Proposals::<T>::insert(
-				i.clone(),
-				Proposal::<T>::new(i, proposer.clone(), H256([0;32]), 100_000u32.into()));
-		}
+		let new_proposer: T::AccountId = account("recipient", 0, SEED);
+		let _ = Voting::<T>::register_voter(admin_origin::<T>(), new_proposer.clone());
+		let deposit = T::ProposalDeposit::get() + T::Currency::minimum_balance();
+		let _ = T::Currency::make_free_balance_be(&proposer, deposit);
+		let _ = T::Currency::make_free_balance_be(&new_proposer, deposit);
+		let _ = Voting::<T>::make_proposal(
+			RawOrigin::Signed(proposer.clone()).into(), H256([1;32]), 100_000u32.into(), None, None, None, Vec::new(), None);
+		let id = Voting::<T>::get_proposal_counter();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(proposer), id, new_proposer.clone());
 
-		let id = x-1;
+		//verify
+		assert_eq!(Voting::<T>::get_proposal(&id).unwrap().proposer, new_proposer);
+	}
+
+	#[benchmark]
+	fn increase_proposal_time(){
+		//setup
+		let proposer = get_registered_proposer::<T>();
+		let id = 1;
+		Proposals::<T>::insert(
+			id,
+			Proposal::<T>::new(id, proposer.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
 		let time_period: u32 = 200000;
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(proposer), id.clone(), time_period.into());
+		_(RawOrigin::Signed(proposer), id, time_period.into());
 
 		//verify
 		let updated_proposal = Voting::<T>::get_proposal(&id);
@@ -75,38 +166,352 @@ pub mod benchmarks {
 	}
 
 	#[benchmark]
-	fn cancel_proposal(x: Linear<1, 10_000>){
-		//setup
+	fn cancel_proposal(v: Linear<0, { T::MaxVotersPerProposal::get() }>){
+		//setup: proposal with v voters to refund
 		let proposer = get_registered_proposer::<T>();
-		for i in 0..x {
-			Proposals::<T>::insert(
-				i.clone(),
-				Proposal::<T>::new(i, proposer.clone(), H256([0;32]), 100_000u32.into()));
+		let id = 1;
+		Proposals::<T>::insert(
+			id,
+			Proposal::<T>::new(id, proposer.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+		for i in 0..v {
+			let voter: T::AccountId = account("voter", i, SEED);
+			let _ = Voting::<T>::force_register_voter(RawOrigin::Root.into(), voter.clone());
+			let _ = T::Currency::make_free_balance_be(&voter, 100u32.into());
+			let _ = Voting::<T>::vote(RawOrigin::Signed(voter).into(), id, VoteDecision::Aye(1));
 		}
 
-		let id = x-1;
 		#[extrinsic_call]
-		_(RawOrigin::Signed(proposer), id.clone());
+		_(RawOrigin::Signed(proposer), id);
 	
 		//verify
 		assert_eq!(
 			Voting::<T>::get_proposal(&id).unwrap().status, 
 			ProposalStatus::Canceled
 		);
+		assert_eq!(Voting::<T>::voter_turnout(&id), 0);
 	}
 
 	#[benchmark]
-	fn vote(){
+	fn vote(x: Linear<1, 10_000>) {
+		//setup: x proposals are stored and the vote goes to the last one
+		let voter_proposer = get_registered_proposer::<T>();
+		for i in 1..=x {
+			Proposals::<T>::insert(i, Proposal::<T>::new(i, voter_proposer.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+		}
+		let _ = T::Currency::make_free_balance_be(&voter_proposer, 100u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter_proposer.clone()), x, VoteDecision::Aye(1));
+
+		//verify
+		assert!(Voting::<T>::vote_casted(&voter_proposer, &x));
+	}
+
+	#[benchmark]
+	fn commit_vote() {
+		//setup
+		let voter = get_registered_proposer::<T>();
+		Proposals::<T>::insert(1, Proposal::<T>::new(1, voter.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+		let _ = T::Currency::make_free_balance_be(&voter, 1_000u32.into());
+		let commitment = Voting::<T>::commitment_of(&1, &voter, &VoteDecision::Aye(1), &[0; 32]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), 1, commitment);
+
+		//verify
+		assert!(Commitments::<T>::contains_key(1, &voter));
+	}
+
+	#[benchmark]
+	fn reveal_vote() {
+		//setup
+		let voter = get_registered_proposer::<T>();
+		Proposals::<T>::insert(1, Proposal::<T>::new(1, voter.clone(), H256([0;32]), 10u32.into(), 0u32.into(), None, None));
+		let _ = T::Currency::make_free_balance_be(&voter, 1_000u32.into());
+		let commitment = Voting::<T>::commitment_of(&1, &voter, &VoteDecision::Aye(1), &[0; 32]);
+		let _ = Voting::<T>::commit_vote(RawOrigin::Signed(voter.clone()).into(), 1, commitment);
+		frame_system::Pallet::<T>::set_block_number(10u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), 1, VoteDecision::Aye(1), [0; 32]);
+
+		//verify
+		assert!(Voting::<T>::vote_casted(&voter, &1));
+	}
+
+	fn get_voted_proposal<T: Config>(time_period: u32, points: u32) -> T::AccountId {
+		let voter = get_registered_proposer::<T>();
+		Proposals::<T>::insert(1, Proposal::<T>::new(1, voter.clone(), H256([0;32]), time_period.into(), 0u32.into(), None, None));
+		let _ = T::Currency::make_free_balance_be(&voter, 1_000u32.into());
+		let _ = Voting::<T>::vote(RawOrigin::Signed(voter.clone()).into(), 1, VoteDecision::Aye(points));
+
+		voter
+	}
+
+	#[benchmark]
+	fn update_vote_increase() {
+		//setup
+		let voter = get_voted_proposal::<T>(100_000, 1);
+		let new_points = Voting::<T>::vote_limit();
+
+		#[extrinsic_call]
+		update_vote(RawOrigin::Signed(voter.clone()), 1, VoteDecision::Nay(new_points));
+
+		//verify
+		assert_eq!(Votes::<T>::get(&voter, 1).unwrap().vote_decision, VoteDecision::Nay(new_points));
+	}
+
+	#[benchmark]
+	fn update_vote_decrease() {
+		//setup
+		let voter = get_voted_proposal::<T>(100_000, Voting::<T>::vote_limit());
+
+		#[extrinsic_call]
+		update_vote(RawOrigin::Signed(voter.clone()), 1, VoteDecision::Nay(1));
+
+		//verify
+		assert_eq!(Votes::<T>::get(&voter, 1).unwrap().vote_decision, VoteDecision::Nay(1));
+	}
+
+	#[benchmark]
+	fn switch_vote() {
+		//setup
+		let voter = get_voted_proposal::<T>(100_000, Voting::<T>::vote_limit());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), 1);
+
+		//verify
+		assert_eq!(
+			Votes::<T>::get(&voter, 1).unwrap().vote_decision,
+			VoteDecision::Nay(Voting::<T>::vote_limit())
+		);
+	}
+
+	#[benchmark]
+	fn cancel_vote() {
+		//setup
+		let voter = get_voted_proposal::<T>(100_000, Voting::<T>::vote_limit());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), 1);
+
+		//verify
+		assert!(!Voting::<T>::vote_casted(&voter, &1));
+	}
+
+	#[benchmark]
+	fn finish_proposal() {
+		//setup
+		let voter = get_voted_proposal::<T>(10, 1);
+		frame_system::Pallet::<T>::set_block_number(11u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter), 1);
+
+		//verify
+		assert_ne!(Voting::<T>::get_proposal(&1).unwrap().status, ProposalStatus::InProgress);
+	}
+
+	#[benchmark]
+	fn reopen_proposal() {
+		//setup
+		let proposer = get_registered_proposer::<T>();
+		Proposals::<T>::insert(1, Proposal::<T>::new(1, proposer.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+		let _ = Voting::<T>::cancel_proposal(RawOrigin::Signed(proposer).into(), 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 1, 100u32.into());
+
+		//verify
+		assert_eq!(Voting::<T>::get_proposal(&1).unwrap().status, ProposalStatus::InProgress);
+	}
+
+	#[benchmark]
+	fn unlock_balance() {
+		//setup
+		let voter = get_voted_proposal::<T>(10, Voting::<T>::vote_limit());
+		frame_system::Pallet::<T>::set_block_number(11u32.into());
+		let _ = Voting::<T>::finish_proposal(RawOrigin::Signed(voter.clone()).into(), 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), 1);
+
+		//verify
+		assert!(!Votes::<T>::get(&voter, 1).unwrap().locked);
+	}
+
+	#[benchmark]
+	fn clean_up_proposal(v: Linear<1, { T::MaxVotersPerProposal::get() }>) {
+		//setup: ended proposal with v unlocked votes
+		let proposer = get_registered_proposer::<T>();
+		Proposals::<T>::insert(1, Proposal::<T>::new(1, proposer.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+		for i in 0..v {
+			let voter: T::AccountId = account("voter", i, SEED);
+			let _ = Voting::<T>::force_register_voter(RawOrigin::Root.into(), voter.clone());
+			let _ = T::Currency::make_free_balance_be(&voter, 100u32.into());
+			let _ = Voting::<T>::vote(RawOrigin::Signed(voter.clone()).into(), 1, VoteDecision::Aye(1));
+		}
+		let _ = Voting::<T>::force_finish_proposal(RawOrigin::Root.into(), 1);
+		for voter in Voting::<T>::voters_for(&1) {
+			let _ = Voting::<T>::unlock_balance(RawOrigin::Signed(voter).into(), 1);
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(proposer), 1);
+
+		//verify
+		assert!(!Voting::<T>::proposal_exists(1));
+	}
+
+	#[benchmark]
+	fn force_finish_proposal() {
 		//setup
 		let voter_proposer = get_registered_proposer::<T>();
-		Proposals::<T>::insert(1, Proposal::<T>::new(1, voter_proposer.clone(), H256([0;32]), 100_000u32.into()));
+		Proposals::<T>::insert(1, Proposal::<T>::new(1, voter_proposer.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
 		let _ = T::Currency::make_free_balance_be(&voter_proposer, 100u32.into());
+		let _ = Voting::<T>::vote(RawOrigin::Signed(voter_proposer).into(), 1, VoteDecision::Aye(1));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 1);
+
+		//verify
+		assert_ne!(Voting::<T>::get_proposal(&1).unwrap().status, ProposalStatus::InProgress);
+	}
+
+	#[benchmark]
+	fn set_parameters() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(3), Some(T::MaxVoters::get()), Some(10));
+
+		//verify
+		assert_eq!(Voting::<T>::vote_limit(), 3);
+		assert_eq!(Voting::<T>::removal_threshold(), 10);
+	}
+
+	#[benchmark]
+	fn set_paused() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, true);
+
+		//verify
+		assert!(Paused::<T>::get());
+	}
+
+	#[benchmark]
+	fn delegate(p: Linear<0, { T::MaxProposals::get() }>) {
+		//setup: the delegator moves its delegation while p proposals are in progress, so both
+		// its own votes and the ones of its previous delegatee are looked up
+		let proposer = get_registered_proposer::<T>();
+		let previous: T::AccountId = account("delegatee", 0, SEED);
+		let delegatee: T::AccountId = account("delegatee", 1, SEED);
+		let delegator: T::AccountId = account("delegator", 0, SEED);
+		for voter in [&previous, &delegatee, &delegator] {
+			let _ = Voting::<T>::register_voter(admin_origin::<T>(), voter.clone());
+		}
+		let _ = Voting::<T>::delegate(RawOrigin::Signed(delegator.clone()).into(), previous);
+		for i in 1..=p {
+			Proposals::<T>::insert(i, Proposal::<T>::new(i, proposer.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+			let _ = ActiveProposalIds::<T>::try_append((100_000u32.into(), i));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()), delegatee.clone());
+
+		//verify
+		assert_eq!(Delegations::<T>::get(&delegator), Some(delegatee));
+	}
+
+	#[benchmark]
+	fn undelegate(p: Linear<0, { T::MaxProposals::get() }>) {
+		//setup: the votes of the delegatee are looked up on p proposals in progress
+		let delegatee = get_registered_proposer::<T>();
+		let delegator: T::AccountId = account("delegator", 0, SEED);
+		let _ = Voting::<T>::register_voter(admin_origin::<T>(), delegator.clone());
+		let _ = Voting::<T>::delegate(RawOrigin::Signed(delegator.clone()).into(), delegatee.clone());
+		for i in 1..=p {
+			Proposals::<T>::insert(i, Proposal::<T>::new(i, delegatee.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+			let _ = ActiveProposalIds::<T>::try_append((100_000u32.into(), i));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()));
+
+		//verify
+		assert!(!Delegations::<T>::contains_key(&delegator));
+	}
+
+	#[benchmark]
+	fn set_delegation_cap() {
+		//setup
+		let delegatee = get_registered_proposer::<T>();
+		let origin = admin_origin::<T>();
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(voter_proposer.clone()), 1, VoteDecision::Aye(1));
+		_(origin as T::RuntimeOrigin, delegatee.clone(), Some(1));
+
+		//verify
+		assert_eq!(Voting::<T>::delegation_cap(&delegatee), 1);
+	}
+
+	#[benchmark]
+	fn unlock_balance_batch(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		//setup: n finished proposals with a locked vote from the caller
+		let voter = get_registered_proposer::<T>();
+		let _ = T::Currency::make_free_balance_be(&voter, 100u32.into());
+		let proposal_ids: Vec<ProposalId> = (1..=n).collect();
+		for id in proposal_ids.iter() {
+			Proposals::<T>::insert(id, Proposal::<T>::new(*id, voter.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+			let _ = Voting::<T>::vote(RawOrigin::Signed(voter.clone()).into(), *id, VoteDecision::Aye(1));
+			Proposals::<T>::mutate(id, |proposal| {
+				if let Some(p) = proposal.as_mut() {
+					p.status = ProposalStatus::Passed
+				}
+			});
+		}
+		let proposal_ids: BoundedVec<ProposalId, T::MaxBatchSize> = proposal_ids.try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(voter.clone()), proposal_ids);
+
+		//verify
+		assert!((1..=n).all(|id| !Votes::<T>::get(&voter, id).unwrap().locked));
+	}
+
+	#[benchmark]
+	fn bulk_finish_proposals(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		//setup: n expired proposals in progress
+		let proposer = get_registered_proposer::<T>();
+		let proposal_ids: Vec<ProposalId> = (1..=n).collect();
+		for id in proposal_ids.iter() {
+			Proposals::<T>::insert(id, Proposal::<T>::new(*id, proposer.clone(), H256([0;32]), 10u32.into(), 0u32.into(), None, None));
+		}
+		frame_system::Pallet::<T>::set_block_number(11u32.into());
+		let proposal_ids: BoundedVec<ProposalId, T::MaxBatchSize> = proposal_ids.try_into().unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(proposer), proposal_ids);
+
+		//verify
+		assert!((1..=n).all(|id| Voting::<T>::get_proposal(&id).unwrap().status != ProposalStatus::InProgress));
+	}
+
+	#[benchmark]
+	fn snapshot_tallies(p: Linear<0, { T::MaxSnapshotsPerBlock::get() }>) {
+		//setup: p proposals in progress
+		let proposer = get_registered_proposer::<T>();
+		for i in 1..=p {
+			Proposals::<T>::insert(i, Proposal::<T>::new(i, proposer.clone(), H256([0;32]), 100_000u32.into(), 0u32.into(), None, None));
+			let _ = ActiveProposalIds::<T>::try_append((100_000u32.into(), i));
+		}
+
+		#[block]
+		{
+			Voting::<T>::snapshot_tallies(1u32.into());
+		}
 
 		//verify
-		assert!(Voting::<T>::vote_casted(&voter_proposer, &1));
+		assert!((1..=p).all(|id| Voting::<T>::tally_at(id, 1u32.into()).is_some()));
 	}
 
 	impl_benchmark_test_suite!(Voting, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -10,32 +10,65 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
 mod types;
-pub use types::{Proposal, ProposalStatus, Vote, VoteDecision};
+pub use types::{
+	AlwaysReject, AlwaysTie, Conviction, Proposal, ProposalStateInfo, ProposalStatus, Tally,
+	TieBreakStrategy, Vote, VoteDecision, VoteDirection,
+};
 
 pub type ProposalId = u32;
 
+pub(crate) const LOG_TARGET: &str = "runtime::voting";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use core::cmp::Ordering;
 
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
 	use frame_support::{
+		dispatch::{GetDispatchInfo, PostDispatchInfo},
 		ensure,
 		pallet_prelude::*,
-		traits::{Currency, LockableCurrency, ReservableCurrency},
-		Blake2_128Concat,
+		sp_runtime::{
+			traits::{
+				Dispatchable, Hash, IntegerSquareRoot, Saturating, UniqueSaturatedInto, Zero,
+			},
+			Perbill,
+		},
+		traits::{
+			Currency, LockableCurrency, NamedReservableCurrency, OnUnbalanced, ReservableCurrency,
+		},
+		Blake2_128Concat, PalletId,
 	};
-	use frame_system::{pallet_prelude::{OriginFor, *}};
+	use frame_system::pallet_prelude::{OriginFor, *};
+	use sp_std::{boxed::Box, vec::Vec};
 
-	use crate::{Proposal, ProposalId, ProposalStatus, Vote, VoteDecision, WeightInfo};
+	use crate::{
+		Conviction, Proposal, ProposalId, ProposalStateInfo, ProposalStatus, Tally,
+		TieBreakStrategy, Vote, VoteDecision, VoteDirection, WeightInfo,
+	};
 
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+	pub type TagOf<T> = BoundedVec<u8, <T as Config>::MaxTagLen>;
+
+	/// Storage version of the pallet in this code. Chains whose on-chain version is older need
+	/// to run the migrations of `crate::migrations` up to it.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	/// Largest page of proposals returned by the paginated queries.
+	pub const MAX_PAGE_SIZE: u32 = 100;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -43,21 +76,161 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-		/// Type to access the Balances Pallet.
+		/// Type to access the Balances Pallet. Balance is reserved under a name derived from
+		/// `PalletId`, so it isn't mixed up with the reserves of other pallets.
 		type Currency: Currency<Self::AccountId>
-			+ ReservableCurrency<Self::AccountId>
+			+ NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>
 			+ LockableCurrency<Self::AccountId>;
+		/// Identifier of the pallet, naming the balance it reserves.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Origin allowed to manage the registered voters.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The runtime call a proposal can carry, dispatched if the proposal passes.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+		/// Origin the calls of passed proposals are dispatched with.
+		type EnactmentOrigin: Get<Self::RuntimeOrigin>;
 
 		///Period of time at the end of a proposal during which votes cannot be reduced or
 		/// cancelled.
+		#[pallet::constant]
 		type VoteRemovalThreshold: Get<u32>;
 
 		///The limit of voter that can be registered to vote in the pallet.
+		#[pallet::constant]
 		type MaxVoters: Get<u32>;
 
 		///The limit of points an individual vote can have.
+		#[pallet::constant]
 		type VoteLimit: Get<u32>;
 
+		///The minimum of points an individual vote can have.
+		#[pallet::constant]
+		type MinVoteAmount: Get<u32>;
+
+		///The limit of items that can be submitted in a single batch call.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		///The limit of aye and nay points a single proposal can accumulate.
+		#[pallet::constant]
+		type MaxProposalVotes: Get<u32>;
+
+		///The limit of voters that can have a vote casted on a single proposal. It doesn't
+		/// depend on `MaxVoters`, which `force_register_voter` can exceed.
+		#[pallet::constant]
+		type MaxVotersPerProposal: Get<u32>;
+
+		///Minimum number of voters that must have a vote casted on a proposal for its result to
+		/// be valid.
+		#[pallet::constant]
+		type Quorum: Get<u32>;
+
+		///Number of blocks a proposer has to wait between two of their proposals.
+		#[pallet::constant]
+		type ProposalCooldown: Get<BlockNumberFor<Self>>;
+
+		///Balance reserved from the proposer while their proposal is in progress.
+		#[pallet::constant]
+		type ProposalDeposit: Get<BalanceOf<Self>>;
+
+		///The longest a proposal can last, counted from the block it was made in.
+		#[pallet::constant]
+		type MaxProposalDuration: Get<BlockNumberFor<Self>>;
+
+		///The limit of proposals that can be in progress at the same time.
+		#[pallet::constant]
+		type MaxProposals: Get<u32>;
+
+		///The limit of bytes of the encoded call a proposal can carry.
+		#[pallet::constant]
+		type MaxCallSize: Get<u32>;
+
+		///The limit of tags a proposal can be categorized with.
+		#[pallet::constant]
+		type MaxTags: Get<u32>;
+
+		///The limit of bytes of a single tag.
+		#[pallet::constant]
+		type MaxTagLen: Get<u32>;
+
+		///The limit of stored proposals made by a single account.
+		#[pallet::constant]
+		type MaxProposalsPerProposer: Get<u32>;
+
+		///The limit of proposals a single account can have a locked vote on at the same time.
+		#[pallet::constant]
+		type MaxVotesPerVoter: Get<u32>;
+
+		///Whether proposals are decided by their quadratic voting power instead of their raw
+		/// points.
+		#[pallet::constant]
+		type UseQuadraticTally: Get<bool>;
+
+		///Strategy resolving proposals that end with as many ayes as nays.
+		type TieBreaker: TieBreakStrategy;
+
+		///Number of blocks between snapshots of the tallies of proposals in progress. Zero
+		/// disables the snapshots.
+		#[pallet::constant]
+		type SnapshotInterval: Get<BlockNumberFor<Self>>;
+
+		///The limit of proposals whose tallies are snapshotted in a single block.
+		#[pallet::constant]
+		type MaxSnapshotsPerBlock: Get<u32>;
+
+		///Number of blocks a vote stays locked after the proposal ends for each lock period of
+		/// its conviction.
+		#[pallet::constant]
+		type ConvictionPeriod: Get<BlockNumberFor<Self>>;
+
+		///Number of blocks the balance of every vote stays reserved after the end of the
+		/// proposal, whatever its conviction.
+		#[pallet::constant]
+		type PostResultLock: Get<BlockNumberFor<Self>>;
+
+		///Number of blocks after the end of a proposal during which committed votes can be
+		/// revealed.
+		#[pallet::constant]
+		type RevealPeriod: Get<BlockNumberFor<Self>>;
+
+		///Balance reserved from a voter committing a hidden vote, until the vote is revealed.
+		#[pallet::constant]
+		type CommitmentDeposit: Get<BalanceOf<Self>>;
+
+		///The limit of expired proposals finished automatically in a single block. Expired
+		/// proposals left over are finished in the following blocks.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+
+		///Whether proposers can vote on their own proposals.
+		#[pallet::constant]
+		type ProposerCanVote: Get<bool>;
+
+		///Balance reserved from voters registered with `register_voter_with_deposit` for as long
+		/// as they stay registered.
+		#[pallet::constant]
+		type MembershipDeposit: Get<BalanceOf<Self>>;
+
+		///The limit of voters that can delegate their voting power to a single account, unless
+		/// the admin set a different cap for it.
+		#[pallet::constant]
+		type MaxDelegationsPerAccount: Get<u32>;
+
+		///The shortest a proposal can last, counted from the block it was made in.
+		#[pallet::constant]
+		type MinProposalDuration: Get<BlockNumberFor<Self>>;
+
+		///Number of free voter slots below which a `VoterCapacityWarning` is emitted.
+		#[pallet::constant]
+		type CapacityWarnThreshold: Get<u32>;
+
+		///Handler for the deposits slashed from proposals that ended without quorum.
+		type SlashHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
 		///Weight Information
 		type WeightInfo: WeightInfo;
 	}
@@ -66,6 +239,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type RegisteredVoters<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
 
+	///Holds the membership deposit reserved from each voter registered with a deposit.
+	#[pallet::storage]
+	pub type MembershipDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
 	///Current amount of registered voters
 	#[pallet::storage]
 	pub type AmountVoters<T: Config> = StorageValue<_, u32>;
@@ -77,40 +255,246 @@ pub mod pallet {
 	///Holds the votes made by registered voters for a specific proposal. The first key is the
 	/// T::AccountId of the voter, and the second key is the ProposalId.
 	#[pallet::storage]
-	pub type Votes<T: Config> =
-		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, ProposalId, Vote>;
+	pub type Votes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ProposalId,
+		Vote<BalanceOf<T>>,
+	>;
+
+	///Holds the voters that currently have a vote casted on a proposal, identified by its
+	/// ProposalId.
+	#[pallet::storage]
+	pub type VotersByProposal<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		BoundedVec<T::AccountId, T::MaxVotersPerProposal>,
+	>;
+
+	///Holds the proposals made by each account that are still in storage.
+	#[pallet::storage]
+	pub type ProposalsByProposer<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<ProposalId, T::MaxProposalsPerProposer>,
+	>;
+
+	///Holds the block in which each account made their last proposal.
+	#[pallet::storage]
+	pub type LastProposalBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
 
 	///Holds the counter used to increase the ProposalId of proposals.
 	#[pallet::storage]
 	pub type ProposalCounter<T: Config> = StorageValue<_, ProposalId>;
 
+	///Holds the end block and id of the last expired proposal looked at by `on_idle`. The next
+	/// block resumes looking for expired proposals to finish right after it.
+	#[pallet::storage]
+	pub type NextExpiryCursor<T: Config> = StorageValue<_, (BlockNumberFor<T>, ProposalId)>;
+
+	///Holds the end block and id of the last proposal whose tallies were snapshotted. The next
+	/// snapshot block resumes right after it.
+	#[pallet::storage]
+	pub type NextSnapshotCursor<T: Config> = StorageValue<_, (BlockNumberFor<T>, ProposalId)>;
+
+	///Holds the voter each registered voter delegated their voting power to. The key is the
+	/// delegator and the value the delegatee.
+	#[pallet::storage]
+	pub type Delegations<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	///Number of voters that delegated their voting power to an account.
+	#[pallet::storage]
+	pub type IncomingDelegations<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	///Cap of incoming delegations set by the admin for an account, taking precedence over
+	/// `Config::MaxDelegationsPerAccount`.
+	#[pallet::storage]
+	pub type DelegationCapOverride<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	///Value set by root that takes precedence over `Config::VoteLimit`.
+	#[pallet::storage]
+	pub type VoteLimitOverride<T: Config> = StorageValue<_, u32>;
+
+	///Value set by root that takes precedence over `Config::MaxVoters`. It can't be higher than
+	/// `Config::MaxVoters`.
+	#[pallet::storage]
+	pub type MaxVotersOverride<T: Config> = StorageValue<_, u32>;
+
+	///Value set by root that takes precedence over `Config::VoteRemovalThreshold`.
+	#[pallet::storage]
+	pub type VoteRemovalThresholdOverride<T: Config> = StorageValue<_, u32>;
+
+	///Number of proposals that are currently in progress.
+	#[pallet::storage]
+	pub type ActiveProposalCount<T: Config> = StorageValue<_, u32>;
+
+	///End blocks and ids of the proposals that are currently in progress, ordered by end block,
+	/// so they can be walked without iterating every stored proposal and the expired ones come
+	/// first.
+	#[pallet::storage]
+	pub type ActiveProposalIds<T: Config> =
+		StorageValue<_, BoundedVec<(BlockNumberFor<T>, ProposalId), T::MaxProposals>, ValueQuery>;
+
+	///Number of proposals that reached each status.
+	#[pallet::storage]
+	pub type StatusCounts<T: Config> = StorageMap<_, Blake2_128Concat, ProposalStatus, u32>;
+
+	///Number of individual voters that currently have a vote casted on a proposal.
+	#[pallet::storage]
+	pub type VoteCount<T: Config> = StorageMap<_, Blake2_128Concat, ProposalId, u32>;
+
+	///Number of votes of each account whose balance is still locked.
+	#[pallet::storage]
+	pub type ActiveVotes<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	///Number of proposals in progress that reach their end in each block.
+	#[pallet::storage]
+	pub type ExpiringAt<T: Config> = StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, u32>;
+
+	///Holds the voters that got their balance back before the proposal ended, by canceling
+	/// their vote or because the proposal was canceled.
+	#[pallet::storage]
+	pub type CanceledVotes<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ProposalId, Blake2_128Concat, T::AccountId, ()>;
+
+	///Hidden votes committed on a proposal, holding the hash of the vote decision and salt along
+	/// with the deposit reserved for it.
+	#[pallet::storage]
+	pub type Commitments<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		(T::Hash, BalanceOf<T>),
+	>;
+
+	///Ayes and nays of a proposal as they stood at a block.
+	#[pallet::storage]
+	pub type TallySnapshots<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProposalId,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		(u32, u32),
+	>;
+
+	///Set by root to pause voting activity. While paused no proposals can be made and no votes
+	/// can be casted or updated.
+	#[pallet::storage]
+	pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	///Whether a `VoterCapacityWarning` was emitted since the free voter slots last dropped below
+	/// `Config::CapacityWarnThreshold`.
+	#[pallet::storage]
+	pub type CapacityWarningIssued<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	///Total balance reserved for the locked votes of every voter.
+	#[pallet::storage]
+	pub type TotalReserved<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	///Encoded call carried by a proposal in progress, dispatched if the proposal passes.
+	#[pallet::storage]
+	pub type EnactmentCalls<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProposalId, BoundedVec<u8, T::MaxCallSize>>;
+
+	///Ids of the proposals in progress categorized with a tag.
+	#[pallet::storage]
+	pub type ProposalsByTag<T: Config> =
+		StorageMap<_, Blake2_128Concat, TagOf<T>, BoundedVec<ProposalId, T::MaxProposals>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		///New voter 'T::AccountId' registered by root into the RegisteredVoters list.
-		VoterRegistered { who: T::AccountId },
-		///A user submitted a new proposal
-		ProposalSubmitted { proposal_id: ProposalId, who: T::AccountId },
+		///New voter 'T::AccountId' registered into the RegisteredVoters list, making 'total'
+		/// registered voters.
+		VoterRegistered { who: T::AccountId, total: u32 },
+		///A membership deposit of 'deposit' was reserved from the newly registered voter.
+		MembershipDepositReserved { who: T::AccountId, deposit: BalanceOf<T> },
+		///Voter removed from the RegisteredVoters list, leaving 'total' registered voters, and
+		/// got back their membership deposit, if any.
+		VoterUnregistered { who: T::AccountId, total: u32, deposit_released: BalanceOf<T> },
+		///Only 'remaining' voters can still be registered before reaching the maximum number of
+		/// voters.
+		VoterCapacityWarning { remaining: u32 },
+		///A user submitted a new proposal that ends at block 'end_block'
+		ProposalSubmitted {
+			proposal_id: ProposalId,
+			who: T::AccountId,
+			end_block: BlockNumberFor<T>,
+		},
 		///A registered voter casted a vote for a specific proposal
-		VoteCasted { proposal_id: ProposalId, who: T::AccountId },
+		VoteCasted { proposal_id: ProposalId, who: T::AccountId, vote_decision: VoteDecision },
+		///A voter committed a hidden vote on a proposal, to be revealed after its end
+		VoteCommitted { proposal_id: ProposalId, who: T::AccountId },
 		///Registered voter updated their vote for Proposal ID from 'previous' to 'new' decision.
+		/// 'amount_delta' is the change in points, negative for reductions, and
+		/// 'direction_changed' flags votes that switched sides.
 		VoteUpdated {
 			proposal_id: ProposalId,
 			who: T::AccountId,
 			previous: VoteDecision,
 			new: VoteDecision,
+			amount_delta: i64,
+			direction_changed: bool,
 		},
 		///A voter canceled his vote for an ongoing proposal
-		VoteCanceled { proposal_id: ProposalId, who: T::AccountId },
-		///Proposal ended and result is defined
-		ProposalEnded { proposal_id: ProposalId, status: ProposalStatus },
+		VoteCanceled { proposal_id: ProposalId, who: T::AccountId, vote_decision: VoteDecision },
+		///Proposal ended and result is defined. 'proposer_still_registered' flags proposals whose
+		///proposer was unregistered after making them
+		ProposalEnded {
+			proposal_id: ProposalId,
+			status: ProposalStatus,
+			proposer_still_registered: bool,
+		},
+		///Final tallies of a proposal that ended, emitted right before 'ProposalEnded'
+		VotesTallied { proposal_id: ProposalId, ayes: u32, nays: u32, abstains: u32 },
+		///The call of a passed proposal was dispatched with the given 'result'
+		ProposalEnacted { proposal_id: ProposalId, result: DispatchResult },
+		///The proposer 'from' handed the control of a proposal over to 'to'
+		ProposalOwnershipTransferred {
+			proposal_id: ProposalId,
+			from: T::AccountId,
+			to: T::AccountId,
+		},
+		///'due_this_block' proposals reached their end in this block, out of 'total_active'
+		/// proposals in progress
+		PendingExpiries { due_this_block: u32, total_active: u32 },
 		///Proposal end time updated for Proposal ID: 'ProposalId' with new end block as
 		/// 'BlockNumberFor<T>'
 		ProposalUpdated { proposal_id: ProposalId, end_block: BlockNumberFor<T> },
 		///Proposal canceled by the proposer
 		ProposalCanceled { proposal_id: ProposalId },
+		///Canceled proposal put back in progress until 'end_block'
+		ProposalReopened { proposal_id: ProposalId, end_block: BlockNumberFor<T> },
+		///Proposal is no longer in progress and its 'affected_voters' can unlock their balance
+		ProposalSettled { proposal_id: ProposalId, affected_voters: u32 },
+		///The deposit of the proposal was returned to its proposer
+		ProposalDepositRefunded { proposal_id: ProposalId, who: T::AccountId, amount: BalanceOf<T> },
+		///The deposit of the proposal was slashed because it ended without quorum
+		ProposalDepositSlashed { proposal_id: ProposalId, who: T::AccountId, amount: BalanceOf<T> },
 		///User unlocked balance of a specific proposal
 		BalanceUnlocked { proposal_id: ProposalId, who: T::AccountId },
+		///User unlocked the balance of 'unlocked' proposals in a single batch
+		BatchBalanceUnlocked { who: T::AccountId, unlocked: u32 },
+		///Root updated the parameters of the pallet. Holds the values in effect after the update.
+		ParametersUpdated { vote_limit: u32, max_voters: u32, removal_threshold: u32 },
+		///The cap of incoming delegations of 'who' was set to 'cap'.
+		DelegationCapSet { who: T::AccountId, cap: u32 },
+		///Ended proposal and its votes were removed from storage
+		ProposalPurged { proposal_id: ProposalId },
+		///Voter 'who' delegated their voting power to 'to'
+		Delegated { who: T::AccountId, to: T::AccountId },
+		///Voter 'who' took back the voting power delegated to 'from'
+		Undelegated { who: T::AccountId, from: T::AccountId },
+		///Root paused or resumed voting activity
+		PausedStateChanged { paused: bool },
 	}
 
 	#[pallet::error]
@@ -127,6 +511,8 @@ pub mod pallet {
 		VoteNotFound,
 		///Vote amount exceeds the defined limit.
 		VoteAmountLimit,
+		///Vote amount is below the minimum vote amount.
+		VoteAmountTooLow,
 		///Invalid vote amount. The number of points exceeds accepted limits.
 		InvalidVoteAmount,
 		///The received amount of votes to update is invalid.
@@ -135,6 +521,18 @@ pub mod pallet {
 		TimePeriodToLow,
 		///The proposal counter reached overflow limit
 		ProposalIdToHigh,
+		///The proposer made a proposal too recently and has to wait for the cooldown to end.
+		ProposalCooldownActive,
+		///The proposal would last longer than the maximum proposal duration.
+		ProposalDurationTooLong,
+		///The maximum number of proposals in progress has been reached.
+		TooManyProposals,
+		///The proposer reached the limit of stored proposals per proposer.
+		TooManyProposalsForProposer,
+		///The proposal description is the default hash.
+		EmptyDescription,
+		///The minimum turnout of the proposal is above the vote cap of proposals.
+		MinTurnoutTooHigh,
 		///Proposal not found. The requested proposal does not exist.
 		ProposalNotFound,
 		///Unauthorized user. The user lacks permission to execute the extrinsic.
@@ -143,12 +541,147 @@ pub mod pallet {
 		ProposalAlreadyEnded,
 		///The balance for the current vote has already been released.
 		BalanceAlreadyUnocked,
+		///The conviction lock of the vote has not expired yet.
+		ConvictionLocked,
+		///The balance of the vote stays locked for some blocks after the end of the proposal.
+		StillLocked,
+		///The vote was canceled and its balance already returned.
+		AlreadyRefunded,
 		///The proposal's remaining time has exceeded the limit for reducing or cancelling votes.
 		PassedRemovalThreshold,
 		///The proposal is ongoing, so the balance cannot be released.
 		ProposalInProgress,
 		///Overflow when performing an operation
 		Overflow,
+		///Voters can't delegate to themselves.
+		DelegationCycle,
+		///The voter has not delegated their voting power.
+		NotDelegating,
+		///The voter delegated their voting power and cannot vote until they undelegate.
+		VoteDelegated,
+		///Some voters of the proposal have not unlocked their balance yet.
+		CannotPurgeWithLockedFunds,
+		///Abstentions have no direction to switch.
+		CannotSwitchAbstention,
+		///The proposal reached the maximum amount of aye and nay points.
+		ProposalVoteCapReached,
+		///The maximum number of voters can't be lower than the number of registered voters.
+		MaxVotersBelowRegistered,
+		///The maximum number of voters can't be higher than the configured one.
+		MaxVotersAboveBound,
+		///Voting activity is paused.
+		Paused,
+		///The encoded call is longer than the configured maximum.
+		CallTooLarge,
+		///The stored call of the proposal can't be decoded anymore.
+		UndecodableCall,
+		///The voter can't reserve the balance required by the vote.
+		InsufficientBalanceForVote,
+		///Tags can't be empty.
+		EmptyTag,
+		///The tag is longer than the configured maximum.
+		TagTooLong,
+		///The proposal has more tags than the configured maximum.
+		TooManyTags,
+		///The tag already categorizes the maximum number of proposals in progress.
+		TooManyProposalsWithTag,
+		///The voter reached the limit of proposals with a locked vote.
+		TooManyActiveVotes,
+		///The voter has no hidden vote committed on the proposal.
+		CommitmentNotFound,
+		///The revealed vote and salt don't match the committed hash.
+		RevealMismatch,
+		///Committed votes can only be revealed after the end of the proposal, during the reveal
+		/// period.
+		NotInRevealPhase,
+		///The proposal can't be finished while committed votes can still be revealed.
+		RevealPhaseOngoing,
+		///Proposers are not allowed to vote on their own proposals.
+		ProposerCannotVote,
+		///The delegatee already received as many delegations as it is allowed to.
+		DelegationCapReached,
+		///The removal threshold of a proposal can't be longer than its duration.
+		RemovalThresholdTooLarge,
+		///The proposal would last less than the minimum proposal duration.
+		ProposalDurationTooShort,
+		///The voter has votes on proposals in progress, which would be counted again through
+		/// the delegatee.
+		VotesInProgress,
+		///The delegatee has votes on proposals in progress that count the delegation.
+		DelegateeVotesInProgress,
+		///The delegatee delegated its own voting power, so it can't vote with the delegation.
+		DelegateeDelegates,
+		///Other voters delegate to the voter, and their delegations would be lost.
+		HasIncomingDelegations,
+		///The proposal already holds votes from the maximum number of voters.
+		TooManyVotersOnProposal,
+		///The proposal already belongs to the account it is transferred to.
+		AlreadyProposer,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			//Accounts for the expiries read and removed in `on_finalize`.
+			let finalize = T::DbWeight::get().reads_writes(2, 1);
+			let interval = T::SnapshotInterval::get();
+			if interval.is_zero() || !(n % interval).is_zero() {
+				return finalize
+			}
+			T::WeightInfo::snapshot_tallies(Self::snapshot_tallies(n)).saturating_add(finalize)
+		}
+
+		fn on_finalize(n: BlockNumberFor<T>) {
+			let due_this_block = <ExpiringAt<T>>::take(n).unwrap_or_default();
+			if due_this_block.is_zero() {
+				return
+			}
+			Self::deposit_event(Event::PendingExpiries {
+				due_this_block,
+				total_active: Self::active_proposal_count(),
+			});
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::finish_expired_proposals(remaining_weight)
+		}
+
+		fn offchain_worker(n: BlockNumberFor<T>) {
+			let proposal_ids = Self::proposals_to_finish();
+			if !proposal_ids.is_empty() {
+				log::debug!(
+					target: crate::LOG_TARGET,
+					"block {:?}: proposals awaiting finish_proposal: {:?}",
+					n,
+					proposal_ids,
+				);
+			}
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::VoteLimit::get().checked_pow(2).is_some(),
+				"VoteLimit is too high, the reserve of a vote (VoteLimit^2) overflows u32"
+			);
+			assert!(T::MaxVoters::get() > 0, "MaxVoters must be greater than zero");
+			assert!(
+				T::MaxVotersPerProposal::get() > 0,
+				"MaxVotersPerProposal must be greater than zero"
+			);
+			assert!(
+				T::MinVoteAmount::get() <= T::VoteLimit::get(),
+				"MinVoteAmount must not be greater than VoteLimit"
+			);
+			assert!(
+				T::MinProposalDuration::get() <= T::MaxProposalDuration::get(),
+				"MinProposalDuration must not be greater than MaxProposalDuration"
+			);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call(weight(<T as Config>::WeightInfo))]
@@ -157,80 +690,294 @@ pub mod pallet {
 		/// if they have not already been registered
 		/// or if the maximum number of voters has not been reached.
 		///
-		/// Origin must be root user.
+		/// Origin must be the `AdminOrigin`.
 		#[pallet::call_index(0)]
 		pub fn register_voter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_registered(&who), Error::<T>::AlreadyRegistered);
+
+			let amount_voters: u32 = <AmountVoters<T>>::try_get().unwrap_or_default();
+			ensure!(amount_voters < Self::max_voters(), Error::<T>::MaxVotersLimitReached);
+
+			Self::do_register_voter(who)
+		}
+
+		/// Registers a voter even if the maximum number of voters has already been reached, for
+		/// migrations and emergencies. This intentionally lets `AmountVoters` exceed `MaxVoters`,
+		/// in which case the regular registration stays blocked until enough voters are gone.
+		///
+		/// Origin must be root user.
+		#[pallet::call_index(20)]
+		pub fn force_register_voter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
 			ensure_root(origin)?;
+			Self::do_register_voter(who)
+		}
+
+		/// Registers a voter like `register_voter` does, reserving `MembershipDeposit` from them
+		/// until they are unregistered. The voter must already have the funds for the deposit.
+		///
+		/// Origin must be the `AdminOrigin`.
+		#[pallet::call_index(27)]
+		pub fn register_voter_with_deposit(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
 			ensure!(!Self::is_registered(&who), Error::<T>::AlreadyRegistered);
 
 			let amount_voters: u32 = <AmountVoters<T>>::try_get().unwrap_or_default();
-			ensure!(amount_voters < T::MaxVoters::get(), Error::<T>::MaxVotersLimitReached);
+			ensure!(amount_voters < Self::max_voters(), Error::<T>::MaxVotersLimitReached);
 
-			//Register voter and increase voter counter
-			<RegisteredVoters<T>>::insert(who.clone(), ());
-			<AmountVoters<T>>::put(amount_voters.saturating_add(1));
+			let deposit = T::MembershipDeposit::get();
+			T::Currency::reserve_named(&Self::reserve_id(), &who, deposit)?;
+			<MembershipDeposits<T>>::insert(&who, deposit);
+
+			Self::do_register_voter(who.clone())?;
+			Self::deposit_event(Event::MembershipDepositReserved { who, deposit });
+			Ok(())
+		}
+
+		/// Removes a voter from the registered voters, returning their membership deposit if they
+		/// were registered with one. Votes of the voter are left as they are.
+		///
+		/// The delegation of the voter and their delegation cap are removed. Fails while other
+		/// voters delegate to them, or while their delegatee has votes on proposals in progress.
+		///
+		/// Origin must be the `AdminOrigin`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(
+			T::WeightInfo::unregister_voter(<ActiveProposalCount<T>>::get().unwrap_or_default())
+		)]
+		pub fn unregister_voter(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+			ensure!(
+				<IncomingDelegations<T>>::get(&who).unwrap_or_default() == 0,
+				Error::<T>::HasIncomingDelegations
+			);
+			let delegatee = <Delegations<T>>::get(&who);
+			if let Some(delegatee) = delegatee.as_ref() {
+				ensure!(
+					!Self::has_votes_in_progress(delegatee),
+					Error::<T>::DelegateeVotesInProgress
+				);
+			}
+
+			let total = <AmountVoters<T>>::get().unwrap_or_default().saturating_sub(1);
+			<RegisteredVoters<T>>::remove(&who);
+			<AmountVoters<T>>::put(total);
+			Self::check_voter_capacity(total);
+
+			let deposit_released = <MembershipDeposits<T>>::take(&who).unwrap_or_default();
+			T::Currency::unreserve_named(&Self::reserve_id(), &who, deposit_released);
+
+			if let Some(from) = delegatee {
+				<Delegations<T>>::remove(&who);
+				<IncomingDelegations<T>>::mutate(&from, |count| {
+					*count = Some(count.unwrap_or_default().saturating_sub(1))
+				});
+				Self::deposit_event(Event::Undelegated { who: who.clone(), from });
+			}
+			<DelegationCapOverride<T>>::remove(&who);
+
+			Self::deposit_event(Event::VoterUnregistered { who, total, deposit_released });
+			Ok(())
+		}
+
+		/// Registers every account of `voters` that is not registered yet. Accounts that are
+		/// already registered are skipped.
+		///
+		/// Fails if registering the new accounts would exceed the maximum number of voters.
+		///
+		/// Origin must be the `AdminOrigin`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::register_voters(voters.len() as u32))]
+		pub fn batch_register_voters(
+			origin: OriginFor<T>,
+			voters: BoundedVec<T::AccountId, T::MaxBatchSize>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut amount_voters: u32 = <AmountVoters<T>>::try_get().unwrap_or_default();
+			for who in voters.into_iter() {
+				if Self::is_registered(&who) {
+					continue
+				}
+				ensure!(amount_voters < Self::max_voters(), Error::<T>::MaxVotersLimitReached);
+
+				amount_voters = Self::insert_voter(who)?;
+			}
+			Self::check_voter_capacity(amount_voters);
 
-			Self::deposit_event(Event::VoterRegistered { who });
 			Ok(())
 		}
 
 		/// Creates a new proposal for voting.
 		/// The proposal contains a hashed description and a voting time limit in blocks.
 		///
-		/// Only registered voters can create proposals.
+		/// Only registered voters can create proposals. The proposer can require a minimum turnout
+		/// stricter than the global quorum, which can't be lowered afterwards, and a lower limit
+		/// of points per voter than the global vote limit. The proposal can also carry a call,
+		/// of at most `MaxCallSize` encoded bytes, that is dispatched if the proposal passes, and
+		/// up to `MaxTags` non-empty tags categorizing it. A removal threshold, bounded by the
+		/// duration of the proposal, can replace the global `VoteRemovalThreshold` for it.
 		#[pallet::call_index(1)]
 		pub fn make_proposal(
 			origin: OriginFor<T>,
 			description: T::Hash,
 			time_period: BlockNumberFor<T>,
+			min_turnout: Option<u32>,
+			per_voter_limit: Option<u32>,
+			call: Option<Box<<T as Config>::RuntimeCall>>,
+			tags: Vec<Vec<u8>>,
+			removal_threshold: Option<u32>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
 			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+			ensure!(description != T::Hash::default(), Error::<T>::EmptyDescription);
+			ensure!(
+				min_turnout.map_or(true, |turnout| turnout <= T::MaxProposalVotes::get()),
+				Error::<T>::MinTurnoutTooHigh
+			);
+			ensure!(per_voter_limit != Some(0), Error::<T>::InvalidVoteAmount);
+			let call: Option<BoundedVec<u8, T::MaxCallSize>> = call
+				.map(|call| call.encode().try_into().map_err(|_| Error::<T>::CallTooLarge))
+				.transpose()?;
+			let tags: BoundedVec<TagOf<T>, T::MaxTags> = tags
+				.into_iter()
+				.map(|tag| {
+					ensure!(!tag.is_empty(), Error::<T>::EmptyTag);
+					TagOf::<T>::try_from(tag).map_err(|_| Error::<T>::TagTooLong)
+				})
+				.collect::<Result<Vec<_>, _>>()?
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyTags)?;
 
 			let current_block_number = <frame_system::Pallet<T>>::block_number();
 			ensure!(time_period > current_block_number, Error::<T>::TimePeriodToLow);
+			ensure!(
+				time_period - current_block_number <= T::MaxProposalDuration::get(),
+				Error::<T>::ProposalDurationTooLong
+			);
+			ensure!(
+				time_period >= current_block_number.saturating_add(T::MinProposalDuration::get()),
+				Error::<T>::ProposalDurationTooShort
+			);
+			ensure!(
+				removal_threshold.map_or(true, |threshold| {
+					BlockNumberFor::<T>::from(threshold) <= time_period - current_block_number
+				}),
+				Error::<T>::RemovalThresholdTooLarge
+			);
+			if let Some(last_proposal_block) = <LastProposalBlock<T>>::get(&who) {
+				ensure!(
+					current_block_number >=
+						last_proposal_block.saturating_add(T::ProposalCooldown::get()),
+					Error::<T>::ProposalCooldownActive
+				);
+			}
 
-			let mut proposal_id: ProposalId = ProposalCounter::<T>::get().unwrap_or_default();
-			ensure!(proposal_id.checked_add(1).is_some(), Error::<T>::ProposalIdToHigh);
-			proposal_id = proposal_id + 1;
-
-			let new_proposal =
-				Proposal::<T>::new(proposal_id, who.clone(), description, time_period);
+			let active_proposals = <ActiveProposalCount<T>>::get().unwrap_or_default();
+			ensure!(active_proposals < T::MaxProposals::get(), Error::<T>::TooManyProposals);
+
+			//Proposal ids start at 1, the counter holds the last id issued.
+			let proposal_id: ProposalId = ProposalCounter::<T>::get()
+				.unwrap_or_default()
+				.checked_add(1)
+				.ok_or(Error::<T>::ProposalIdToHigh)?;
+
+			<ProposalsByProposer<T>>::try_mutate(&who, |proposals| {
+				proposals.get_or_insert_with(Default::default).try_push(proposal_id)
+			})
+			.map_err(|_| Error::<T>::TooManyProposalsForProposer)?;
+			Self::index_tags(proposal_id, &tags)?;
+			Self::index_active(proposal_id, time_period)?;
+
+			let deposit = T::ProposalDeposit::get();
+			T::Currency::reserve_named(&Self::reserve_id(), &who, deposit)?;
+
+			let new_proposal = Proposal::<T> {
+				call_hash: call.as_ref().map(|call| T::Hashing::hash(call)),
+				tags,
+				removal_threshold,
+				..Proposal::<T>::new(
+					proposal_id,
+					who.clone(),
+					description,
+					time_period,
+					deposit,
+					min_turnout,
+					per_voter_limit,
+				)
+			};
 
 			<Proposals<T>>::insert(proposal_id, new_proposal);
+			Self::schedule_expiry(time_period);
+			if let Some(call) = call {
+				<EnactmentCalls<T>>::insert(proposal_id, call);
+			}
 			<ProposalCounter<T>>::put(proposal_id);
-			Self::deposit_event(Event::ProposalSubmitted { proposal_id, who });
+			<LastProposalBlock<T>>::insert(&who, current_block_number);
+			Self::increase_status_count(&ProposalStatus::InProgress);
+			<ActiveProposalCount<T>>::put(active_proposals.saturating_add(1));
+			Self::deposit_event(Event::ProposalSubmitted {
+				proposal_id,
+				who,
+				end_block: time_period,
+			});
 
 			Ok(())
 		}
 
 		/// Extends the voting period of a proposal by increasing its time limit in blocks.
+		/// The proposal can't be extended past `MaxProposalDuration` blocks from its creation, nor
+		/// once it is no longer in progress.
 		///
-		/// Only the user who created the proposal can call this extrinsic.
+		/// Only the user who created the proposal or the `AdminOrigin` can call this extrinsic.
 		#[pallet::call_index(2)]
-		#[pallet::weight(
-			T::WeightInfo::increase_proposal_time(ProposalCounter::<T>::get().unwrap_or_default())
-		)]
 		pub fn increase_proposal_time(
 			origin: OriginFor<T>,
 			proposal_id: ProposalId,
 			new_time_period: BlockNumberFor<T>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+			//The admin can extend any proposal, other callers only their own.
+			let caller = match T::AdminOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => {
+					let who = ensure_signed(origin)?;
+					ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+					Some(who)
+				},
+			};
 
 			let proposal = Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
-			ensure!(proposal.proposer == who, Error::<T>::Unauthorized);
+			if let Some(who) = caller {
+				ensure!(proposal.proposer == who, Error::<T>::Unauthorized);
+			}
+			ensure!(
+				proposal.status == ProposalStatus::InProgress,
+				Error::<T>::ProposalAlreadyEnded
+			);
 
 			let current_block_number = <frame_system::Pallet<T>>::block_number();
 			ensure!(new_time_period > proposal.time_period, Error::<T>::TimePeriodToLow);
 			ensure!(new_time_period > current_block_number, Error::<T>::TimePeriodToLow);
+			ensure!(
+				new_time_period.saturating_sub(proposal.created_at) <=
+					T::MaxProposalDuration::get(),
+				Error::<T>::ProposalDurationTooLong
+			);
 
 			<Proposals<T>>::mutate(proposal_id, |proposal| {
 				if let Some(p) = proposal.as_mut() {
 					p.time_period = new_time_period
 				}
 			});
+			Self::unschedule_expiry(proposal.time_period);
+			Self::schedule_expiry(new_time_period);
+			Self::unindex_active(proposal_id);
+			Self::index_active(proposal_id, new_time_period)?;
 
 			Self::deposit_event(Event::ProposalUpdated { proposal_id, end_block: new_time_period });
 
@@ -239,15 +986,15 @@ pub mod pallet {
 
 		/// Cancel a proposal if it hasn't ended yet
 		///
-		/// The proposal can only be cancelled by the user who created it.
+		/// The proposal can only be cancelled by the user who created it. The reserved balance of
+		/// every voter of the proposal is returned and their votes are removed.
 		#[pallet::call_index(3)]
-		#[pallet::weight(
-			T::WeightInfo::increase_proposal_time(ProposalCounter::<T>::get().unwrap_or_default())
-		)]
+		#[pallet::weight(T::WeightInfo::cancel_proposal(Pallet::<T>::voter_turnout(proposal_id)))]
 		pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let proposal = Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			let mut proposal =
+				Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
 
 			ensure!(proposal.proposer == who, Error::<T>::Unauthorized);
 			ensure!(
@@ -258,77 +1005,159 @@ pub mod pallet {
 			let current_block_number = <frame_system::Pallet<T>>::block_number();
 			ensure!(proposal.time_period > current_block_number, Error::<T>::TimePeriodToLow);
 
-			<Proposals<T>>::mutate(proposal_id, |proposal| {
-				if let Some(p) = proposal.as_mut() {
-					p.status = ProposalStatus::Canceled
-				}
-			});
+			Self::set_status(&mut proposal, ProposalStatus::Canceled);
+			Self::refund_deposit(&proposal);
+			<Proposals<T>>::insert(proposal_id, proposal);
+			<EnactmentCalls<T>>::remove(proposal_id);
 			Self::deposit_event(Event::ProposalCanceled { proposal_id });
 
+			//Settle every voter, a canceled proposal holds no votes.
+			let voters = <VotersByProposal<T>>::take(proposal_id).unwrap_or_default();
+			for voter in voters.iter() {
+				let reserved = Self::reserved_for_proposal(voter, &proposal_id);
+				<Votes<T>>::remove(voter, proposal_id);
+				<CanceledVotes<T>>::insert(proposal_id, voter, ());
+				Self::release_active_vote(voter);
+				Self::unreserve_for_vote(voter, reserved);
+				Self::deposit_event(Event::BalanceUnlocked { proposal_id, who: voter.clone() });
+			}
+			<VoteCount<T>>::remove(proposal_id);
+			Self::release_commitments(proposal_id);
+			Self::deposit_event(Event::ProposalSettled {
+				proposal_id,
+				affected_voters: voters.len() as u32,
+			});
+
 			Ok(())
 		}
 
 		/// Allows a registered voter to vote on a proposal if it's still ongoing. The vote
-		/// increases the ayes or nays votes of the proposal based on the number of vote points.
+		/// increases the ayes, nays or abstains of the proposal based on the number of vote points.
+		/// Abstentions count towards the turnout but not towards the result.
+		///
+		/// The end block of the proposal is exclusive: votes can be casted, updated and canceled
+		/// up to the block before it. See `is_proposal_active`.
 
 		/// To vote, the user must reserve the balance of their account, equal to the square
-		/// of the number of votes they want to cast.
+		/// of the number of votes they want to cast. The weight grows with the number of proposals
+		/// issued, as reading a proposal gets costlier the larger the proposals map is.
 		// The number of votes must be greater than zero and less than the VoteLimit.
 		#[pallet::call_index(4)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::vote(<ProposalCounter<T>>::get().unwrap_or_default()))]
 		pub fn vote(
 			origin: OriginFor<T>,
 			proposal_id: ProposalId,
 			vote_decision: VoteDecision,
 		) -> DispatchResult {
-			//Verify sender is part of register voters
 			let who: T::AccountId = ensure_signed(origin)?;
-			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
-
-			let proposal = Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
-
-			//Check that propossal is not passed removal_treshold
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
-			ensure!(
-				proposal.time_period > current_block_number &&
-					proposal.status == ProposalStatus::InProgress,
-				Error::<T>::ProposalAlreadyEnded
-			);
+			Self::do_vote(who, proposal_id, vote_decision, Conviction::None)
+		}
 
-			//Verify if voter already casted vote
-			ensure!(!Self::vote_casted(&who, &proposal_id), Error::<T>::VoteAlreadyCasted);
+		/// Votes on a proposal with a conviction, multiplying the points added to the tallies in
+		/// exchange for keeping the reserved balance locked for a number of conviction periods
+		/// after the proposal ends. The reserved balance is still the square of the points.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::vote(<ProposalCounter<T>>::get().unwrap_or_default()))]
+		pub fn vote_with_conviction(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			vote_decision: VoteDecision,
+			conviction: Conviction,
+		) -> DispatchResult {
+			let who: T::AccountId = ensure_signed(origin)?;
+			Self::do_vote(who, proposal_id, vote_decision, conviction)
+		}
 
-			let vote_amount = match vote_decision {
-				VoteDecision::Aye(v) => v,
-				VoteDecision::Nay(v) => v,
-			};
+		/// Votes on a proposal with the largest amount of points the caller can afford, that is
+		/// the largest amount whose square fits in their free balance, capped by the vote limit.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::vote(<ProposalCounter<T>>::get().unwrap_or_default()))]
+		pub fn vote_with_max_balance(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			vote_direction: VoteDirection,
+		) -> DispatchResult {
+			let who: T::AccountId = ensure_signed(origin)?;
 
+			let free_balance: u32 = T::Currency::free_balance(&who).unique_saturated_into();
+			let vote_limit = Self::get_proposal(&proposal_id)
+				.map_or_else(Self::vote_limit, |proposal| Self::proposal_vote_limit(&proposal));
+			let vote_amount = free_balance.integer_sqrt().min(vote_limit);
 			ensure!(vote_amount > 0, Error::<T>::InvalidVoteAmount);
-			ensure!(vote_amount <= T::VoteLimit::get(), Error::<T>::VoteAmountLimit);
 
-			//Reserve balance corresponding to vote amount^2.
-			let amount_to_reserve: u32 =
-				(vote_amount).checked_pow(2).ok_or(Error::<T>::Overflow)?;
-			T::Currency::reserve(&who, amount_to_reserve.into())?;
+			Self::do_vote(
+				who,
+				proposal_id,
+				vote_direction.with_points(vote_amount),
+				Conviction::None,
+			)
+		}
 
-			let vote = Vote { vote_decision: vote_decision.clone(), locked: true };
+		/// Commits a hidden vote on a proposal that is still ongoing, to be revealed with
+		/// `reveal_vote` once the proposal ends.
+		///
+		/// The commitment is the hash of the proposal id, the voter, the vote decision, points
+		/// included, and a salt chosen by the voter. See `commitment_of`. Until the vote is
+		/// revealed only `CommitmentDeposit` is reserved from the voter, and the vote
+		/// doesn't count towards the tallies.
+		#[pallet::call_index(25)]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
+			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+			ensure!(!<Delegations<T>>::contains_key(&who), Error::<T>::VoteDelegated);
 
-			//Insert vote and update proposals
-			<Votes<T>>::insert(who.clone(), proposal_id, vote.clone());
+			let proposal = Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(Self::is_proposal_active(&proposal), Error::<T>::ProposalAlreadyEnded);
+			ensure!(Self::can_vote_on(&who, &proposal), Error::<T>::ProposerCannotVote);
+			ensure!(
+				!Self::vote_casted(&who, &proposal_id) &&
+					!<Commitments<T>>::contains_key(proposal_id, &who),
+				Error::<T>::VoteAlreadyCasted
+			);
 
-			<Proposals<T>>::mutate(proposal_id, |proposal| {
-				if let Some(p) = proposal.as_mut() {
-					match vote_decision {
-						VoteDecision::Aye(v) => p.ayes += v,
-						VoteDecision::Nay(v) => p.nays += v,
-					}
-				}
-			});
+			let deposit = T::CommitmentDeposit::get();
+			T::Currency::reserve_named(&Self::reserve_id(), &who, deposit)?;
+			<Commitments<T>>::insert(proposal_id, &who, (commitment, deposit));
 
-			Self::deposit_event(Event::VoteCasted { proposal_id, who });
+			Self::deposit_event(Event::VoteCommitted { proposal_id, who });
 			Ok(())
 		}
 
+		/// Reveals a vote committed with `commit_vote`, casting it on the proposal like `vote`
+		/// does.
+		///
+		/// Votes can only be revealed during the `RevealPeriod` blocks that follow the end block of
+		/// the proposal. The commitment deposit is returned and the square of the vote points is
+		/// reserved in its place.
+		#[pallet::call_index(26)]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			vote_decision: VoteDecision,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
+
+			let (commitment, deposit) =
+				<Commitments<T>>::get(proposal_id, &who).ok_or(Error::<T>::CommitmentNotFound)?;
+			let proposal = Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(Self::in_reveal_phase(&proposal), Error::<T>::NotInRevealPhase);
+			ensure!(
+				Self::commitment_of(&proposal_id, &who, &vote_decision, &salt) == commitment,
+				Error::<T>::RevealMismatch
+			);
+
+			<Commitments<T>>::remove(proposal_id, &who);
+			T::Currency::unreserve_named(&Self::reserve_id(), &who, deposit);
+			Self::cast_vote(who, proposal, vote_decision, Conviction::None)
+		}
+
 		/// Updates the vote of a voter in a proposal with a new amount of points and the ability
 		/// to switch between aye and nay.
 		///
@@ -336,163 +1165,114 @@ pub mod pallet {
 		/// vote count of the proposal if the new vote differs from the original.
 		///
 		/// - Check that the proposal is still in progress and has not passed the removal threshold.
-		///   If the threshold is surppased the voter cant reduce the amount of votes.
+		///   If the threshold is surppased the voter cant reduce the amount of votes. The end block
+		///   of the proposal is already closed.
 		/// - Calculate the new amount of vote points and update the aye or nay count accordingly.
 		/// - Reserve or unreserve the user's balance based on the comparison between the current
 		///   and new vote amounts.
 		/// - Update the vote record in storage and emit an event for the vote update.
 		#[pallet::call_index(5)]
-		#[pallet::weight(0)]
+		#[pallet::weight(
+			T::WeightInfo::update_vote_increase().max(T::WeightInfo::update_vote_decrease())
+		)]
 		pub fn update_vote(
 			origin: OriginFor<T>,
 			proposal_id: ProposalId,
 			new_vote_decision: VoteDecision,
 		) -> DispatchResult {
-			//Verify sender is part of register voters and vote exists
 			let who: T::AccountId = ensure_signed(origin)?;
-			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+			Self::do_update_vote(who, proposal_id, new_vote_decision)
+		}
 
-			let mut proposal =
-				Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
-			//Check that propossal is not passed removal_treshold
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
-			ensure!(
-				proposal.time_period > current_block_number &&
-					proposal.status == ProposalStatus::InProgress,
-				Error::<T>::ProposalAlreadyEnded
-			);
-
-			//Get vote and verify if it exists
+		/// Switches the vote of a voter between aye and nay keeping its amount of points, so the
+		/// reserved balance doesn't change. Abstentions can't be switched.
+		///
+		/// Follows the same rules as `update_vote`.
+		#[pallet::call_index(16)]
+		pub fn switch_vote(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			let who: T::AccountId = ensure_signed(origin)?;
 			let current_vote =
 				<Votes<T>>::try_get(&who, &proposal_id).ok().ok_or(Error::<T>::VoteNotFound)?;
 
-			let current_amount: u32 = match current_vote.vote_decision {
-				VoteDecision::Aye(v) => {
-					proposal.ayes = proposal.ayes.saturating_sub(v);
-					v
-				},
-				VoteDecision::Nay(v) => {
-					proposal.nays = proposal.nays.saturating_sub(v);
-					v
-				},
-			};
-
-			let new_amount = match new_vote_decision {
-				VoteDecision::Aye(v) => {
-					proposal.ayes += v;
-					v
-				},
-				VoteDecision::Nay(v) => {
-					proposal.nays += v;
-					v
-				},
-			};
-			if new_amount.cmp(&current_amount) == Ordering::Less {
-				//Check threshold
-				ensure!(
-					!Self::passed_removal_threshold(&proposal.time_period),
-					Error::<T>::PassedRemovalThreshold
-				);
-			}
-
-			ensure!(new_amount != 0, Error::<T>::InvalidUpdateAmount);
-			ensure!(new_amount <= T::VoteLimit::get(), Error::<T>::VoteAmountLimit);
-
-			let current_amount_pow: u32 =
-				current_amount.checked_pow(2).ok_or(Error::<T>::Overflow)?;
-			let new_amount_pow: u32 = new_amount.checked_pow(2).ok_or(Error::<T>::Overflow)?;
-
-			//Modify reserved amount
-			match new_amount.cmp(&current_amount) {
-				Ordering::Greater => {
-					T::Currency::reserve(&who, (new_amount_pow - current_amount_pow).into())?;
-				},
-				Ordering::Less => {
-					T::Currency::unreserve(&who, (current_amount_pow - new_amount_pow).into());
-				},
-				_ => (),
+			let new_vote_decision = match current_vote.vote_decision {
+				VoteDecision::Aye(v) => VoteDecision::Nay(v),
+				VoteDecision::Nay(v) => VoteDecision::Aye(v),
+				VoteDecision::Split { aye, nay } => VoteDecision::Split { aye: nay, nay: aye },
+				VoteDecision::Abstain(_) => return Err(Error::<T>::CannotSwitchAbstention.into()),
 			};
-
-			let new_vote = Vote { vote_decision: new_vote_decision, locked: true };
-
-			<Votes<T>>::insert(who.clone(), proposal_id, new_vote.clone());
-			<Proposals<T>>::insert(proposal_id, proposal);
-			Self::deposit_event(Event::VoteUpdated {
-				proposal_id,
-				who,
-				previous: current_vote.vote_decision,
-				new: new_vote.vote_decision,
-			});
-
-			Ok(())
+			Self::do_update_vote(who, proposal_id, new_vote_decision)
 		}
 
 		///Enables a voter to revoke their vote for a proposal, provided that the RemovalThreshold
-		///has not been surpassed.
+		///has not been surpassed and the end block of the proposal was not reached.
 		///
 		/// It then updates the count of votes in favor (ayes) or against (nays) accordingly.
 		///
 		/// Returns the reserved balance to the voter
 		#[pallet::call_index(9)]
-		#[pallet::weight(0)]
 		pub fn cancel_vote(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
 			let who: T::AccountId = ensure_signed(origin)?;
 			//Allows to calculate treshold
 
 			let mut proposal =
 				Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
-			let vote: Vote = <Votes<T>>::try_get(who.clone(), proposal_id)
+			let vote = <Votes<T>>::try_get(who.clone(), proposal_id)
 				.ok()
 				.ok_or(Error::<T>::VoteNotFound)?;
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
 
-			ensure!(
-				proposal.time_period >= current_block_number &&
-					proposal.status == ProposalStatus::InProgress,
-				Error::<T>::ProposalAlreadyEnded
-			);
+			ensure!(Self::is_proposal_active(&proposal), Error::<T>::ProposalAlreadyEnded);
 
 			//Check that propossal is not passed removal_treshold
-			ensure!(
-				!Self::passed_removal_threshold(&proposal.time_period),
-				Error::<T>::PassedRemovalThreshold
-			);
+			ensure!(!Self::passed_removal_threshold(&proposal), Error::<T>::PassedRemovalThreshold);
 
-			match vote.vote_decision {
-				VoteDecision::Aye(v) => proposal.ayes = proposal.ayes.saturating_sub(v),
-				VoteDecision::Nay(v) => proposal.nays = proposal.nays.saturating_sub(v),
-			}
+			Self::remove_from_tally(&mut proposal, &vote)?;
 
 			<Proposals<T>>::insert(proposal_id, proposal);
 			<Votes<T>>::remove(who.clone(), proposal_id);
+			<CanceledVotes<T>>::insert(proposal_id, &who, ());
+			<VotersByProposal<T>>::mutate(proposal_id, |voters| {
+				if let Some(v) = voters.as_mut() {
+					v.retain(|voter| *voter != who)
+				}
+			});
+			<VoteCount<T>>::mutate(proposal_id, |count| {
+				*count = Some(count.unwrap_or_default().saturating_sub(1))
+			});
+			Self::release_active_vote(&who);
 
-			let vote_amount = match vote.vote_decision {
-				VoteDecision::Aye(v) => v,
-				VoteDecision::Nay(v) => v,
-			};
-
-			//unreserve balance corresponding to the vote (amount^2).
-			let amount_to_unreserve: u32 =
-				(vote_amount).checked_pow(2).ok_or(Error::<T>::Overflow)?;
-			T::Currency::unreserve(&who, amount_to_unreserve.into());
+			Self::unreserve_for_vote(&who, vote.reserved);
 
-			Self::deposit_event(Event::VoteCanceled { proposal_id, who });
+			Self::deposit_event(Event::VoteCanceled {
+				proposal_id,
+				who,
+				vote_decision: vote.vote_decision,
+			});
 
 			Ok(())
 		}
 
-		/// Finishes a proposal by calculating the result based on the number of ayes and nays.
+		/// Finishes a proposal by calculating the result based on the number of ayes and nays,
+		/// or on their quadratic voting power if `UseQuadraticTally` is set (see
+		/// `quadratic_power`). Abstentions are not part of the comparison, but abstaining voters
+		/// count towards the quorum. If fewer voters than the quorum took part the proposal
+		/// ends as `NoQuorum`, and if nobody voted at all it ends as `Expired`.
+		///
+		/// The deposit of the proposer is refunded, unless the proposal ended without quorum, in
+		/// which case it is slashed. If the proposal passed, the call it carries is dispatched
+		/// with the `EnactmentOrigin`.
 		///
 		/// The proposal can only be finished if the time limit (in blocks) has been
 		/// exceeded and the status of the proposal is 'In Progress'.
 		///
-		/// This extrinsic can be called by any registered voter.
+		/// This extrinsic can be called by any signed account, registered or not, so that keepers
+		/// can finalize expired proposals.
 		#[pallet::call_index(7)]
-		#[pallet::weight(0)]
+		#[pallet::weight(
+			T::WeightInfo::finish_proposal()
+				.saturating_add(Pallet::<T>::enactment_weight(*proposal_id))
+		)]
 		pub fn finish_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
-			//Verify sender is part of register voters and vote exists
-			let who: T::AccountId = ensure_signed(origin)?;
-			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+			ensure_signed(origin)?;
 
 			let mut proposal =
 				Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
@@ -503,17 +1283,130 @@ pub mod pallet {
 					proposal.status == ProposalStatus::InProgress,
 				Error::<T>::ProposalAlreadyEnded
 			);
+			ensure!(!Self::awaiting_reveals(&proposal), Error::<T>::RevealPhaseOngoing);
 
-			let voting_result: ProposalStatus = match proposal.ayes.cmp(&proposal.nays) {
-				Ordering::Less => ProposalStatus::Rejected,
-				Ordering::Greater => ProposalStatus::Passed,
-				Ordering::Equal => ProposalStatus::Tied,
-			};
+			Self::do_finish_proposal(proposal);
+			Ok(())
+		}
+
+		/// Finishes a proposal before its time limit, calculating the result from its current
+		/// tallies like `finish_proposal` does.
+		///
+		/// Origin must be root user.
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			T::WeightInfo::force_finish_proposal()
+				.saturating_add(Pallet::<T>::enactment_weight(*proposal_id))
+		)]
+		pub fn force_finish_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let proposal = Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(
+				proposal.status == ProposalStatus::InProgress,
+				Error::<T>::ProposalAlreadyEnded
+			);
+
+			Self::do_finish_proposal(proposal);
+			Ok(())
+		}
+
+		/// Puts a canceled proposal back in progress until `new_time_period`, to recover from a
+		/// cancellation made by mistake.
+		///
+		/// The proposal starts over without votes, as they were all settled when it was canceled,
+		/// and without deposit or call, as they were returned and dropped along with them. Finished
+		/// proposals can't be reopened. The new duration is checked like the one of a new proposal,
+		/// including against the removal threshold of the proposal.
+		///
+		/// Origin must be root user.
+		#[pallet::call_index(24)]
+		pub fn reopen_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			new_time_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
 
-			proposal.status = voting_result.clone();
+			let mut proposal =
+				Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.status != ProposalStatus::InProgress, Error::<T>::ProposalInProgress);
+			ensure!(proposal.status == ProposalStatus::Canceled, Error::<T>::ProposalAlreadyEnded);
 
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(new_time_period > current_block_number, Error::<T>::TimePeriodToLow);
+			ensure!(
+				new_time_period - current_block_number <= T::MaxProposalDuration::get(),
+				Error::<T>::ProposalDurationTooLong
+			);
+			ensure!(
+				new_time_period >=
+					current_block_number.saturating_add(T::MinProposalDuration::get()),
+				Error::<T>::ProposalDurationTooShort
+			);
+			ensure!(
+				proposal.removal_threshold.map_or(true, |threshold| {
+					BlockNumberFor::<T>::from(threshold) <= new_time_period - current_block_number
+				}),
+				Error::<T>::RemovalThresholdTooLarge
+			);
+			let active_proposals = <ActiveProposalCount<T>>::get().unwrap_or_default();
+			ensure!(active_proposals < T::MaxProposals::get(), Error::<T>::TooManyProposals);
+
+			proposal.time_period = new_time_period;
+			proposal.tally = Tally::default();
+			proposal.quadratic_ayes = 0;
+			proposal.quadratic_nays = 0;
+			proposal.deposit = Zero::zero();
+			proposal.call_hash = None;
+			Self::index_active(proposal_id, new_time_period)?;
+			Self::index_tags(proposal_id, &proposal.tags)?;
+			Self::set_status(&mut proposal, ProposalStatus::InProgress);
 			<Proposals<T>>::insert(proposal_id, proposal);
-			Self::deposit_event(Event::ProposalEnded { proposal_id, status: voting_result });
+
+			Self::deposit_event(Event::ProposalReopened {
+				proposal_id,
+				end_block: new_time_period,
+			});
+			Ok(())
+		}
+
+		/// Finishes several expired proposals at once, like `finish_proposal` does for each of
+		/// them.
+		///
+		/// Proposals that don't exist, are still within their time limit or already ended are
+		/// skipped instead of failing the whole batch.
+		///
+		/// This extrinsic can be called by any signed account, registered or not.
+		#[pallet::call_index(23)]
+		#[pallet::weight(
+			T::WeightInfo::bulk_finish_proposals(proposal_ids.len() as u32).saturating_add(
+				proposal_ids.iter().fold(Weight::zero(), |weight, proposal_id| {
+					weight.saturating_add(Pallet::<T>::enactment_weight(*proposal_id))
+				})
+			)
+		)]
+		pub fn bulk_finish_proposals(
+			origin: OriginFor<T>,
+			proposal_ids: BoundedVec<ProposalId, T::MaxBatchSize>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			for proposal_id in proposal_ids.into_iter() {
+				match Self::get_proposal(&proposal_id) {
+					Some(proposal)
+						if proposal.time_period < current_block_number &&
+							proposal.status == ProposalStatus::InProgress &&
+							!Self::awaiting_reveals(&proposal) =>
+						Self::do_finish_proposal(proposal),
+					_ => (),
+				}
+			}
+
 			Ok(())
 		}
 
@@ -523,37 +1416,289 @@ pub mod pallet {
 		/// Returns an error if the proposal is still in progress or if the balance
 		/// has already been unlocked.
 		#[pallet::call_index(8)]
-		#[pallet::weight(0)]
 		pub fn unlock_balance(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			let proposal: Proposal<T> =
+			Self::do_unlock_balance(&who, proposal_id)?;
+
+			Self::deposit_event(Event::BalanceUnlocked { proposal_id, who });
+
+			Ok(())
+		}
+
+		///Unlocks the locked balance of the caller for several finished proposals at once.
+		///
+		/// Proposals that are still in progress, that the caller didn't vote on or whose balance
+		/// was already unlocked are skipped instead of failing the whole batch.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::unlock_balance_batch(proposal_ids.len() as u32))]
+		pub fn unlock_balance_batch(
+			origin: OriginFor<T>,
+			proposal_ids: BoundedVec<ProposalId, T::MaxBatchSize>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut unlocked: u32 = 0;
+			for proposal_id in proposal_ids.into_iter() {
+				if Self::do_unlock_balance(&who, proposal_id).is_err() {
+					continue
+				}
+				unlocked = unlocked.saturating_add(1);
+				Self::deposit_event(Event::BalanceUnlocked { proposal_id, who: who.clone() });
+			}
+
+			Self::deposit_event(Event::BatchBalanceUnlocked { who, unlocked });
+			Ok(())
+		}
+
+		/// Overrides the limits defined in the pallet configuration. Parameters passed as `None`
+		/// keep their current value.
+		///
+		/// `max_voters` can't be lower than the number of registered voters nor higher than
+		/// `Config::MaxVoters`.
+		///
+		/// Origin must be root user.
+		#[pallet::call_index(15)]
+		pub fn set_parameters(
+			origin: OriginFor<T>,
+			vote_limit: Option<u32>,
+			max_voters: Option<u32>,
+			removal_threshold: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			if let Some(max_voters) = max_voters {
+				ensure!(max_voters <= T::MaxVoters::get(), Error::<T>::MaxVotersAboveBound);
+				ensure!(
+					max_voters >= <AmountVoters<T>>::get().unwrap_or_default(),
+					Error::<T>::MaxVotersBelowRegistered
+				);
+				<MaxVotersOverride<T>>::put(max_voters);
+			}
+			if let Some(vote_limit) = vote_limit {
+				<VoteLimitOverride<T>>::put(vote_limit);
+			}
+			if let Some(removal_threshold) = removal_threshold {
+				<VoteRemovalThresholdOverride<T>>::put(removal_threshold);
+			}
+
+			Self::deposit_event(Event::ParametersUpdated {
+				vote_limit: Self::vote_limit(),
+				max_voters: Self::max_voters(),
+				removal_threshold: Self::removal_threshold(),
+			});
+			Ok(())
+		}
+
+		/// Hands the control of a proposal in progress, like extending or canceling it, over to
+		/// another registered voter. The proposal moves to the proposals of the new owner along
+		/// with its deposit, which is reserved from the new owner and returned to the previous one.
+		///
+		/// Can only be called by the current proposer.
+		#[pallet::call_index(22)]
+		pub fn transfer_proposal_ownership(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			new_proposer: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_registered(&new_proposer), Error::<T>::VoterIsNotRegistered);
+
+			let mut proposal =
 				Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.proposer == who, Error::<T>::Unauthorized);
+			ensure!(new_proposer != who, Error::<T>::AlreadyProposer);
+			ensure!(Self::is_proposal_active(&proposal), Error::<T>::ProposalAlreadyEnded);
+
+			<ProposalsByProposer<T>>::try_mutate(&new_proposer, |proposals| {
+				proposals.get_or_insert_with(Default::default).try_push(proposal_id)
+			})
+			.map_err(|_| Error::<T>::TooManyProposalsForProposer)?;
+			T::Currency::reserve_named(&Self::reserve_id(), &new_proposer, proposal.deposit)?;
+			T::Currency::unreserve_named(&Self::reserve_id(), &who, proposal.deposit);
+			<ProposalsByProposer<T>>::mutate_exists(&who, |proposals| {
+				if let Some(ids) = proposals {
+					ids.retain(|id| *id != proposal_id);
+					if ids.is_empty() {
+						*proposals = None;
+					}
+				}
+			});
+
+			proposal.proposer = new_proposer.clone();
+			<Proposals<T>>::insert(proposal_id, proposal);
+
+			Self::deposit_event(Event::ProposalOwnershipTransferred {
+				proposal_id,
+				from: who,
+				to: new_proposer,
+			});
+			Ok(())
+		}
+
+		/// Pauses or resumes voting activity without a runtime upgrade. While paused proposals
+		/// can't be made and votes can't be casted, updated or switched. Operations returning
+		/// funds, like canceling a vote or unlocking balance, remain available.
+		///
+		/// Origin must be root user.
+		#[pallet::call_index(21)]
+		pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			ensure_root(origin)?;
+
+			<Paused<T>>::put(paused);
+
+			Self::deposit_event(Event::PausedStateChanged { paused });
+			Ok(())
+		}
+
+		/// Removes an ended proposal along with its votes from storage. Can be called by anyone
+		/// once every voter of the proposal unlocked their balance.
+		#[pallet::call_index(17)]
+		#[pallet::weight(
+			T::WeightInfo::clean_up_proposal(Pallet::<T>::voter_turnout(proposal_id))
+		)]
+		pub fn clean_up_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let proposal = Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
 			ensure!(proposal.status != ProposalStatus::InProgress, Error::<T>::ProposalInProgress);
 
-			let mut vote: Vote = <Votes<T>>::try_get(who.clone(), proposal_id)
-				.ok()
-				.ok_or(Error::<T>::VoteNotFound)?;
-			ensure!(vote.locked, Error::<T>::BalanceAlreadyUnocked);
-			vote.locked = false;
-			<Votes<T>>::insert(who.clone(), proposal_id, vote.clone());
+			let voters = Self::voters_for(&proposal_id);
+			ensure!(
+				voters.iter().all(|who| {
+					<Votes<T>>::get(who, proposal_id).map_or(true, |vote| !vote.locked)
+				}),
+				Error::<T>::CannotPurgeWithLockedFunds
+			);
 
-			let vote_amount = match vote.vote_decision {
-				VoteDecision::Aye(v) => v,
-				VoteDecision::Nay(v) => v,
-			};
+			for who in voters.iter() {
+				<Votes<T>>::remove(who, proposal_id);
+			}
+			<VotersByProposal<T>>::remove(proposal_id);
+			<VoteCount<T>>::remove(proposal_id);
+			let _ = <TallySnapshots<T>>::clear_prefix(proposal_id, u32::MAX, None);
+			let _ = <CanceledVotes<T>>::clear_prefix(proposal_id, u32::MAX, None);
+			Self::release_commitments(proposal_id);
+			<ProposalsByProposer<T>>::mutate_exists(&proposal.proposer, |proposals| {
+				if let Some(ids) = proposals {
+					ids.retain(|id| *id != proposal_id);
+					if ids.is_empty() {
+						*proposals = None;
+					}
+				}
+			});
+			<Proposals<T>>::remove(proposal_id);
 
-			//unreserve balance corresponding to the vote (amount^2).
-			let amount_to_unreserve: u32 =
-				(vote_amount).checked_pow(2).ok_or(Error::<T>::Overflow)?;
-			T::Currency::unreserve(&who, amount_to_unreserve.into());
+			Self::deposit_event(Event::ProposalPurged { proposal_id });
+			Ok(())
+		}
 
-			Self::deposit_event(Event::BalanceUnlocked { proposal_id, who });
+		/// Delegates the voting power of the caller to another registered voter.
+		///
+		/// While the delegation is active the caller cannot vote, and every vote the delegatee
+		/// casts or updates counts once more for each of its delegators. Delegation lends voting
+		/// power, not funds: only the delegatee reserves balance for its votes. Votes casted
+		/// before the delegation keep the power they were tallied with.
+		///
+		/// The caller can't delegate while it has votes or commitments on proposals in
+		/// progress, as they would be counted again through the delegatee. Delegations don't
+		/// chain: the delegatee can't delegate itself, and a voter that received delegations
+		/// can't delegate them further.
+		///
+		/// Delegating again replaces the previous delegation, which follows the rules of
+		/// `undelegate`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			T::WeightInfo::delegate(<ActiveProposalCount<T>>::get().unwrap_or_default())
+		)]
+		pub fn delegate(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+			ensure!(Self::is_registered(&to), Error::<T>::VoterIsNotRegistered);
+			ensure!(who != to, Error::<T>::DelegationCycle);
+			ensure!(!<Delegations<T>>::contains_key(&to), Error::<T>::DelegateeDelegates);
+			ensure!(
+				<IncomingDelegations<T>>::get(&who).unwrap_or_default() == 0,
+				Error::<T>::HasIncomingDelegations
+			);
+			ensure!(!Self::has_votes_in_progress(&who), Error::<T>::VotesInProgress);
+
+			//Delegating again to the same account doesn't take a new slot.
+			let previous = <Delegations<T>>::get(&who);
+			if let Some(previous) = previous.as_ref().filter(|previous| **previous != to) {
+				ensure!(
+					!Self::has_votes_in_progress(previous),
+					Error::<T>::DelegateeVotesInProgress
+				);
+			}
+			ensure!(
+				previous.as_ref() == Some(&to) ||
+					<IncomingDelegations<T>>::get(&to).unwrap_or_default() <
+						Self::delegation_cap(&to),
+				Error::<T>::DelegationCapReached
+			);
+
+			if let Some(previous) = previous {
+				<IncomingDelegations<T>>::mutate(&previous, |count| {
+					*count = Some(count.unwrap_or_default().saturating_sub(1))
+				});
+			}
+			<Delegations<T>>::insert(&who, &to);
+			<IncomingDelegations<T>>::mutate(&to, |count| {
+				*count = Some(count.unwrap_or_default().saturating_add(1))
+			});
+
+			Self::deposit_event(Event::Delegated { who, to });
+			Ok(())
+		}
 
+		/// Removes the delegation of the caller, allowing them to vote again.
+		///
+		/// The delegation can't be removed while the delegatee has votes on proposals in
+		/// progress, as those votes were tallied counting it.
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			T::WeightInfo::undelegate(<ActiveProposalCount<T>>::get().unwrap_or_default())
+		)]
+		pub fn undelegate(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let from = <Delegations<T>>::get(&who).ok_or(Error::<T>::NotDelegating)?;
+			ensure!(!Self::has_votes_in_progress(&from), Error::<T>::DelegateeVotesInProgress);
+
+			<Delegations<T>>::remove(&who);
+
+			<IncomingDelegations<T>>::mutate(&from, |count| {
+				*count = Some(count.unwrap_or_default().saturating_sub(1))
+			});
+
+			Self::deposit_event(Event::Undelegated { who, from });
+			Ok(())
+		}
+
+		/// Sets how many voters can delegate to `who`, overriding
+		/// `Config::MaxDelegationsPerAccount` for them. Passing `None` goes back to the configured
+		/// cap. Delegations received before lowering the cap are kept.
+		///
+		/// Origin must be the `AdminOrigin`.
+		#[pallet::call_index(29)]
+		pub fn set_delegation_cap(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			cap: Option<u32>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			<DelegationCapOverride<T>>::set(&who, cap);
+
+			Self::deposit_event(Event::DelegationCapSet { cap: Self::delegation_cap(&who), who });
 			Ok(())
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Name of the balance reserved by the pallet, for deposits and votes alike.
+		pub fn reserve_id() -> [u8; 8] {
+			T::PalletId::get().0
+		}
 		pub fn is_registered(who: &T::AccountId) -> bool {
 			RegisteredVoters::<T>::contains_key(who)
 		}
@@ -561,23 +1706,1069 @@ pub mod pallet {
 		pub fn proposal_exists(proposal_id: ProposalId) -> bool {
 			Proposals::<T>::contains_key(proposal_id)
 		}
+		/// Storage version currently stored on chain, which lags behind `STORAGE_VERSION` until
+		/// the pending migrations are run.
+		pub fn storage_version() -> StorageVersion {
+			StorageVersion::get::<Pallet<T>>()
+		}
+		/// Highest proposal id issued so far. Ended and purged proposals are still accounted
+		/// for, see `active_proposal_count` for the proposals in progress.
 		pub fn get_proposal_counter() -> ProposalId {
 			ProposalCounter::<T>::get().unwrap_or_default()
 		}
+		/// Number of proposals currently in progress.
+		pub fn active_proposal_count() -> u32 {
+			<ActiveProposalCount<T>>::get().unwrap_or_default()
+		}
+		/// Number of registered voters.
+		pub fn voter_count() -> u32 {
+			<AmountVoters<T>>::get().unwrap_or_default()
+		}
 		pub fn get_proposal(proposal_id: &ProposalId) -> Option<Proposal<T>> {
 			<Proposals<T>>::get(proposal_id)
 		}
-		pub fn vote_casted(who: &T::AccountId, proposal_id: &ProposalId) -> bool {
-			if <Votes<T>>::try_get(who, proposal_id).is_err() {
-				return false
-			};
-			true
+		/// Limit of points of a single vote, set by root or taken from the configuration.
+		pub fn vote_limit() -> u32 {
+			<VoteLimitOverride<T>>::get().unwrap_or_else(T::VoteLimit::get)
 		}
-		pub fn passed_removal_threshold(end_time_period: &BlockNumberFor<T>) -> bool {
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
+		/// Limit of points per voter on the proposal: the vote limit, lowered by the limit set by
+		/// the proposer if any.
+		pub fn proposal_vote_limit(proposal: &Proposal<T>) -> u32 {
+			let vote_limit = Self::vote_limit();
+			proposal.per_voter_limit.map_or(vote_limit, |limit| limit.min(vote_limit))
+		}
+		/// Limit of registered voters, set by root or taken from the configuration.
+		pub fn max_voters() -> u32 {
+			<MaxVotersOverride<T>>::get().unwrap_or_else(T::MaxVoters::get)
+		}
+		/// Removal threshold of proposals, set by root or taken from the configuration.
+		pub fn removal_threshold() -> u32 {
+			<VoteRemovalThresholdOverride<T>>::get().unwrap_or_else(T::VoteRemovalThreshold::get)
+		}
+		/// Number of proposals that reached `status`. Proposals are counted only under their
+		/// current status, and purged proposals keep being counted under their final one.
+		pub fn count_with_status(status: &ProposalStatus) -> u32 {
+			<StatusCounts<T>>::get(status).unwrap_or_default()
+		}
+		/// Proposals made by `who` that are still in storage.
+		pub fn proposals_of(who: &T::AccountId) -> Vec<ProposalId> {
+			<ProposalsByProposer<T>>::get(who).unwrap_or_default().into_inner()
+		}
+		/// Ids of the proposals in progress categorized with `tag`.
+		pub fn proposals_with_tag(tag: &[u8]) -> Vec<ProposalId> {
+			TagOf::<T>::try_from(tag.to_vec())
+				.ok()
+				.and_then(<ProposalsByTag<T>>::get)
+				.map_or_else(Vec::new, |ids| ids.into_inner())
+		}
+		/// Up to `limit` proposals that are no longer in progress, walking the ids from `start`.
+		/// The page is capped to `MAX_PAGE_SIZE` entries.
+		///
+		/// Meant for off-chain and RPC use only, as it may read every proposal after `start`.
+		pub fn finished_proposals(start: ProposalId, limit: u32) -> Vec<(ProposalId, Proposal<T>)> {
+			(start..=Self::get_proposal_counter())
+				.filter_map(|proposal_id| {
+					Self::get_proposal(&proposal_id).map(|proposal| (proposal_id, proposal))
+				})
+				.filter(|(_, proposal)| proposal.status != ProposalStatus::InProgress)
+				.take(limit.min(MAX_PAGE_SIZE) as usize)
+				.collect()
+		}
+		/// Up to `limit` proposals that can still be voted on, walking the ids after
+		/// `start_after`, or from the first one if `None`. The page is capped to `MAX_PAGE_SIZE`
+		/// entries, and the id of its last proposal is the cursor of the next page.
+		///
+		/// Meant for off-chain and RPC use only, as it may read every proposal after the cursor.
+		pub fn active_proposals(
+			start_after: Option<ProposalId>,
+			limit: u32,
+		) -> Vec<(ProposalId, Proposal<T>)> {
+			(start_after.unwrap_or_default()..=Self::get_proposal_counter())
+				.filter(|proposal_id| start_after.map_or(true, |after| *proposal_id > after))
+				.filter_map(|proposal_id| {
+					Self::get_proposal(&proposal_id).map(|proposal| (proposal_id, proposal))
+				})
+				.filter(|(_, proposal)| Self::is_proposal_active(proposal))
+				.take(limit.min(MAX_PAGE_SIZE) as usize)
+				.collect()
+		}
+		/// Voters that currently have a vote casted on the proposal.
+		pub fn voters_for(proposal_id: &ProposalId) -> Vec<T::AccountId> {
+			<VotersByProposal<T>>::get(proposal_id).unwrap_or_default().into_inner()
+		}
+		/// Number of proposals `who` has a locked vote on, bounded by `MaxVotesPerVoter`.
+		pub fn active_votes_of(who: &T::AccountId) -> u32 {
+			<ActiveVotes<T>>::get(who).unwrap_or_default()
+		}
+		/// Number of voters that currently have a vote casted on the proposal.
+		pub fn voter_turnout(proposal_id: &ProposalId) -> u32 {
+			<VoteCount<T>>::get(proposal_id).unwrap_or_default()
+		}
+		pub fn get_vote(
+			who: &T::AccountId,
+			proposal_id: &ProposalId,
+		) -> Option<Vote<BalanceOf<T>>> {
+			<Votes<T>>::get(who, proposal_id)
+		}
+		/// Balance `who` has reserved for their vote on the proposal, the square of its points.
+		/// Zero if they haven't voted on it or already unlocked their balance.
+		pub fn reserved_for_proposal(who: &T::AccountId, proposal_id: &ProposalId) -> BalanceOf<T> {
+			match <Votes<T>>::get(who, proposal_id) {
+				Some(vote) if vote.locked => vote.reserved,
+				_ => Zero::zero(),
+			}
+		}
+		/// Balance reserved by the pallet for the locked votes of every voter. Deposits of
+		/// proposals, commitments and memberships are not included.
+		pub fn total_locked() -> BalanceOf<T> {
+			<TotalReserved<T>>::get()
+		}
+		/// Balance `who` has reserved across all their locked votes.
+		///
+		/// Iterates every vote of `who`, so it is meant for off-chain and RPC use only and must
+		/// not be called from dispatchables.
+		pub fn total_reserved(who: &T::AccountId) -> BalanceOf<T> {
+			<Votes<T>>::iter_prefix(who).fold(
+				Zero::zero(),
+				|total: BalanceOf<T>, (proposal_id, _)| {
+					total.saturating_add(Self::reserved_for_proposal(who, &proposal_id))
+				},
+			)
+		}
+		pub fn vote_casted(who: &T::AccountId, proposal_id: &ProposalId) -> bool {
+			if <Votes<T>>::try_get(who, proposal_id).is_err() {
+				return false
+			};
+			true
+		}
+		/// Whether `who` is allowed to vote on the proposal, which is always the case unless
+		/// `ProposerCanVote` is off and they made it.
+		pub fn can_vote_on(who: &T::AccountId, proposal: &Proposal<T>) -> bool {
+			T::ProposerCanVote::get() || proposal.proposer != *who
+		}
+		/// Hash committed with `commit_vote` by `who` for a vote decision and salt on the
+		/// proposal. The proposal and the voter are part of it, so a commitment seen on chain
+		/// can't be copied by another voter or onto another proposal.
+		pub fn commitment_of(
+			proposal_id: &ProposalId,
+			who: &T::AccountId,
+			vote_decision: &VoteDecision,
+			salt: &[u8; 32],
+		) -> T::Hash {
+			T::Hashing::hash_of(&(proposal_id, who, vote_decision, salt))
+		}
+		/// Whether committed votes can be revealed on the proposal: it is still in progress and
+		/// the current block is within `RevealPeriod` blocks from its end block.
+		pub fn in_reveal_phase(proposal: &Proposal<T>) -> bool {
+			let now = <frame_system::Pallet<T>>::block_number();
+			proposal.status == ProposalStatus::InProgress &&
+				proposal.time_period <= now &&
+				now < proposal.time_period.saturating_add(T::RevealPeriod::get())
+		}
+		/// Whether the proposal has committed votes that can still be revealed, in which case it
+		/// can't be finished yet.
+		pub fn awaiting_reveals(proposal: &Proposal<T>) -> bool {
+			<frame_system::Pallet<T>>::block_number() <
+				proposal.time_period.saturating_add(T::RevealPeriod::get()) &&
+				<Commitments<T>>::iter_prefix_values(proposal.id).next().is_some()
+		}
+		/// Returns the deposits of the votes committed on the proposal and never revealed.
+		fn release_commitments(proposal_id: ProposalId) {
+			for (who, (_, deposit)) in <Commitments<T>>::drain_prefix(proposal_id) {
+				T::Currency::unreserve_named(&Self::reserve_id(), &who, deposit);
+			}
+		}
+		/// Blocks left until the end block of a proposal in progress, zero once it was reached or
+		/// the proposal ended. `None` if the proposal doesn't exist.
+		pub fn time_remaining(proposal_id: &ProposalId) -> Option<BlockNumberFor<T>> {
+			let proposal = Self::get_proposal(proposal_id)?;
+			if proposal.status != ProposalStatus::InProgress {
+				return Some(Zero::zero())
+			}
+			Some(proposal.time_period.saturating_sub(<frame_system::Pallet<T>>::block_number()))
+		}
+		/// Whether the proposal can still be voted on. Voting closes at the end block itself, so a
+		/// proposal is active while it is in progress and the current block is before its end.
+		pub fn is_proposal_active(proposal: &Proposal<T>) -> bool {
+			proposal.status == ProposalStatus::InProgress &&
+				proposal.time_period > <frame_system::Pallet<T>>::block_number()
+		}
+		/// Status the proposal would end with if it was finished with its current tallies.
+		/// Proposals without any vote end as `Expired` and proposals whose turnout is below the
+		/// quorum end as `NoQuorum`. Ties are resolved by the configured `TieBreaker`.
+		pub fn project_status(proposal: &Proposal<T>) -> ProposalStatus {
+			if proposal.tally.is_empty() {
+				return ProposalStatus::Expired
+			}
+			if Self::missed_quorum(proposal) {
+				return ProposalStatus::NoQuorum
+			}
+			let (ayes, nays) = if T::UseQuadraticTally::get() {
+				(proposal.quadratic_ayes, proposal.quadratic_nays)
+			} else {
+				(proposal.tally.ayes, proposal.tally.nays)
+			};
+			match ayes.cmp(&nays) {
+				Ordering::Less => ProposalStatus::Rejected,
+				Ordering::Greater => ProposalStatus::Passed,
+				Ordering::Equal => T::TieBreaker::resolve_tie(),
+			}
+		}
+		/// Status the proposal would get if it was finished after adding a vote with `decision` to
+		/// its current tallies, without touching storage or balances. The vote counts with a
+		/// multiplier of one, and the quorum is checked against the current turnout.
+		pub fn simulate_vote(
+			proposal_id: &ProposalId,
+			decision: &VoteDecision,
+		) -> Option<ProposalStatus> {
+			let mut proposal = Self::get_proposal(proposal_id)?;
+			let (quadratic_ayes, quadratic_nays) = Self::quadratic_power(decision, 1);
+			let vote = Vote {
+				vote_decision: decision.clone(),
+				locked: true,
+				multiplier: 1,
+				conviction: Conviction::None,
+				quadratic_ayes,
+				quadratic_nays,
+				reserved: Zero::zero(),
+			};
+			Self::add_to_tally(&mut proposal, &vote).ok()?;
+			Some(Self::project_status(&proposal))
+		}
+		/// Ayes and nays of the proposal as they were snapshotted at `block`, if a snapshot was
+		/// taken at that block.
+		pub fn tally_at(proposal_id: ProposalId, block: BlockNumberFor<T>) -> Option<(u32, u32)> {
+			<TallySnapshots<T>>::get(proposal_id, block)
+		}
+		/// Snapshots the tallies of up to `MaxSnapshotsPerBlock` proposals in progress at block
+		/// `n`. Returns the number of proposals read, which is what the weight is charged for.
+		///
+		/// Only the proposals listed in `ActiveProposalIds` are read, so the cost doesn't grow
+		/// with the number of ended proposals kept in storage. Each snapshot block resumes after
+		/// `NextSnapshotCursor`, so every proposal gets its turn when more than
+		/// `MaxSnapshotsPerBlock` are in progress.
+		pub(crate) fn snapshot_tallies(n: BlockNumberFor<T>) -> u32 {
+			let active = <ActiveProposalIds<T>>::get();
+			let mut read = 0;
+			let mut cursor = None;
+			for entry in Self::after_cursor(&active, <NextSnapshotCursor<T>>::get())
+				.take(T::MaxSnapshotsPerBlock::get() as usize)
+			{
+				cursor = Some(*entry);
+				read += 1;
+				let proposal = match Self::get_proposal(&entry.1) {
+					Some(proposal) => proposal,
+					None => continue,
+				};
+				<TallySnapshots<T>>::insert(
+					proposal.id,
+					n,
+					(proposal.tally.ayes, proposal.tally.nays),
+				);
+			}
+			if cursor.is_some() {
+				<NextSnapshotCursor<T>>::set(cursor);
+			}
+			read
+		}
+		/// Entries of `ActiveProposalIds` starting right after `cursor` and wrapping around to the
+		/// first one, so walks spread over several blocks visit every entry in turn.
+		fn after_cursor(
+			entries: &[(BlockNumberFor<T>, ProposalId)],
+			cursor: Option<(BlockNumberFor<T>, ProposalId)>,
+		) -> impl Iterator<Item = &(BlockNumberFor<T>, ProposalId)> {
+			let start =
+				cursor.map_or(0, |cursor| entries.partition_point(|entry| *entry <= cursor));
+			entries[start..].iter().chain(entries[..start].iter())
+		}
+		/// Ids of the expired proposals that are still in progress and can be finished with
+		/// `finish_proposal`, for keepers to act on.
+		///
+		/// Reads the proposals of `ActiveProposalIds` that already ended, ordered by end block.
+		pub fn proposals_to_finish() -> Vec<ProposalId> {
+			let now = <frame_system::Pallet<T>>::block_number();
+			<ActiveProposalIds<T>>::get()
+				.into_iter()
+				.take_while(|(end, _)| *end < now)
+				.filter_map(|(_, proposal_id)| Self::get_proposal(&proposal_id))
+				.filter(|proposal| !Self::awaiting_reveals(proposal))
+				.map(|proposal| proposal.id)
+				.collect()
+		}
+		/// Finishes expired proposals that are still in progress, like `finish_proposal` does, for
+		/// as long as `remaining_weight` allows it and up to `MaxExpiriesPerBlock` of them, which
+		/// bounds the weight used to `MaxExpiriesPerBlock` times the cost of finishing a proposal.
+		///
+		/// `ActiveProposalIds` is ordered by end block, so only the proposals that already ended
+		/// are read. The walk resumes after `NextExpiryCursor` and wraps around to the first
+		/// expired proposal, so proposals that can't be finished yet, like the ones awaiting
+		/// reveals or enacting a call heavier than the budget, don't hold back the ones after
+		/// them. Returns the weight used.
+		pub(crate) fn finish_expired_proposals(remaining_weight: Weight) -> Weight {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let read = T::DbWeight::get().reads(1);
+			let max_expiries = T::MaxExpiriesPerBlock::get();
+			let remaining_weight = remaining_weight
+				.min(T::WeightInfo::finish_proposal().saturating_mul(max_expiries.into()));
+			//Accounts for the active proposals and the cursor read, and the cursor written back.
+			let mut used = T::DbWeight::get().reads_writes(2, 1);
+			if !used.all_lte(remaining_weight) {
+				return Weight::zero()
+			}
+			let active = <ActiveProposalIds<T>>::get();
+			let ended = &active[..active.partition_point(|(end, _)| *end < now)];
+			let mut expired = Vec::new();
+			let mut cursor = None;
+
+			for entry in Self::after_cursor(ended, <NextExpiryCursor<T>>::get()) {
+				if expired.len() as u32 >= max_expiries ||
+					!used.saturating_add(read).all_lte(remaining_weight)
+				{
+					break
+				}
+				used.saturating_accrue(read);
+				let proposal = match Self::get_proposal(&entry.1) {
+					Some(proposal) if !Self::awaiting_reveals(&proposal) => proposal,
+					_ => {
+						cursor = Some(*entry);
+						continue
+					},
+				};
+				cursor = Some(*entry);
+				//Proposals whose call doesn't fit are left for `finish_proposal` or a later walk.
+				let finish = T::WeightInfo::finish_proposal()
+					.saturating_add(Self::enactment_weight(proposal.id));
+				if !used.saturating_add(finish).all_lte(remaining_weight) {
+					continue
+				}
+				used.saturating_accrue(finish);
+				expired.push(proposal);
+			}
+
+			if cursor.is_some() {
+				<NextExpiryCursor<T>>::set(cursor);
+			}
+			for proposal in expired {
+				Self::do_finish_proposal(proposal);
+			}
+			used
+		}
+		/// Whether fewer voters than the quorum, or than the minimum turnout set by the proposer if
+		/// stricter, have a vote casted on the proposal.
+		pub fn missed_quorum(proposal: &Proposal<T>) -> bool {
+			Self::voter_turnout(&proposal.id) <
+				T::Quorum::get().max(proposal.min_turnout.unwrap_or_default())
+		}
+		/// Current tallies of a proposal along with its projected status. Backs the
+		/// `VotingApi` runtime API.
+		pub fn proposal_state(
+			proposal_id: ProposalId,
+		) -> Option<ProposalStateInfo<BlockNumberFor<T>>> {
+			let proposal = Self::get_proposal(&proposal_id)?;
+			let projected_status = match proposal.status {
+				ProposalStatus::InProgress => Self::project_status(&proposal),
+				status => status,
+			};
+
+			Some(ProposalStateInfo {
+				ayes: proposal.tally.ayes,
+				nays: proposal.tally.nays,
+				abstains: proposal.tally.abstains,
+				turnout: Self::voter_turnout(&proposal_id),
+				time_period: proposal.time_period,
+				projected_status,
+			})
+		}
+		/// Share of the registered voters that have a vote casted on the proposal. Zero if there
+		/// are no registered voters.
+		pub fn participation_rate(proposal_id: &ProposalId) -> Option<Perbill> {
+			if !Self::proposal_exists(*proposal_id) {
+				return None
+			}
+			let registered = Self::voter_count();
+			if registered == 0 {
+				return Some(Perbill::zero())
+			}
+			Some(Perbill::from_rational(Self::voter_turnout(proposal_id), registered))
+		}
+		/// Whether the proposal is currently passing, meaning it has at least as many ayes as nays,
+		/// along with the absolute difference between both tallies.
+		pub fn winner_margin(proposal_id: &ProposalId) -> Option<(bool, u32)> {
+			let proposal = Self::get_proposal(proposal_id)?;
+			Some((
+				proposal.tally.ayes >= proposal.tally.nays,
+				proposal.tally.ayes.abs_diff(proposal.tally.nays),
+			))
+		}
+		/// Shares of the ayes and nays of the proposal over the points casted in either
+		/// direction, abstentions left aside. Both are zero if no aye or nay was casted.
+		pub fn vote_split(proposal_id: &ProposalId) -> Option<(Perbill, Perbill)> {
+			let proposal = Self::get_proposal(proposal_id)?;
+			let ayes = u64::from(proposal.tally.ayes);
+			let nays = u64::from(proposal.tally.nays);
+			let total = ayes.saturating_add(nays);
+			if total == 0 {
+				return Some((Perbill::zero(), Perbill::zero()))
+			}
+			Some((Perbill::from_rational(ayes, total), Perbill::from_rational(nays, total)))
+		}
+		/// Weight of dispatching the call carried by the proposal, zero if it carries none.
+		pub fn enactment_weight(proposal_id: ProposalId) -> Weight {
+			<EnactmentCalls<T>>::get(proposal_id)
+				.and_then(|call| <T as Config>::RuntimeCall::decode(&mut &call[..]).ok())
+				.map_or_else(Weight::zero, |call| call.get_dispatch_info().weight)
+		}
+		/// Block from which the balance of `vote` can be unlocked, once its conviction lock
+		/// periods elapsed after the end of the proposal.
+		pub fn conviction_unlock_block(
+			proposal: &Proposal<T>,
+			vote: &Vote<BalanceOf<T>>,
+		) -> BlockNumberFor<T> {
+			let lock_periods: BlockNumberFor<T> = vote.conviction.lock_periods().into();
+			proposal
+				.time_period
+				.saturating_add(lock_periods.saturating_mul(T::ConvictionPeriod::get()))
+		}
+		/// Block from which the votes of an ended proposal can be unlocked, `PostResultLock` blocks
+		/// after its end. Proposals finished before their end block count as ended when finished.
+		pub fn post_result_unlock_block(proposal: &Proposal<T>) -> BlockNumberFor<T> {
+			proposal
+				.ended_at
+				.map_or(proposal.time_period, |ended_at| ended_at.min(proposal.time_period))
+				.saturating_add(T::PostResultLock::get())
+		}
+		/// Multiplier applied to the points of the votes casted by `who`: one for the voter
+		/// itself plus one for each voter that delegated to them.
+		pub fn voting_multiplier(who: &T::AccountId) -> u32 {
+			<IncomingDelegations<T>>::get(who).unwrap_or_default().saturating_add(1)
+		}
+		/// Number of voters that can delegate to `who`, set by the admin or taken from the
+		/// configuration.
+		pub fn delegation_cap(who: &T::AccountId) -> u32 {
+			<DelegationCapOverride<T>>::get(who).unwrap_or_else(T::MaxDelegationsPerAccount::get)
+		}
+		/// Whether `who` voted or committed a vote on any of the proposals in progress.
+		fn has_votes_in_progress(who: &T::AccountId) -> bool {
+			<ActiveProposalIds<T>>::get().iter().any(|(_, proposal_id)| {
+				<Votes<T>>::contains_key(who, proposal_id) ||
+					<Commitments<T>>::contains_key(proposal_id, who)
+			})
+		}
+		/// Casts the vote of `who` on the proposal, reserving the square of its points.
+		fn do_vote(
+			who: T::AccountId,
+			proposal_id: ProposalId,
+			vote_decision: VoteDecision,
+			conviction: Conviction,
+		) -> DispatchResult {
+			ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
+			//Verify sender is part of register voters
+			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+
+			ensure!(!<Delegations<T>>::contains_key(&who), Error::<T>::VoteDelegated);
+
+			let proposal = Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+
+			ensure!(Self::is_proposal_active(&proposal), Error::<T>::ProposalAlreadyEnded);
+			ensure!(
+				!<Commitments<T>>::contains_key(proposal_id, &who),
+				Error::<T>::VoteAlreadyCasted
+			);
+
+			Self::cast_vote(who, proposal, vote_decision, conviction)
+		}
+		/// Adds a new vote of `who` to the tallies of the proposal and reserves the square of its
+		/// points. Callers check that the proposal can take the vote.
+		fn cast_vote(
+			who: T::AccountId,
+			mut proposal: Proposal<T>,
+			vote_decision: VoteDecision,
+			conviction: Conviction,
+		) -> DispatchResult {
+			let proposal_id = proposal.id;
+			ensure!(Self::can_vote_on(&who, &proposal), Error::<T>::ProposerCannotVote);
+
+			//Verify if voter already casted vote
+			ensure!(!Self::vote_casted(&who, &proposal_id), Error::<T>::VoteAlreadyCasted);
+			ensure!(
+				Self::active_votes_of(&who) < T::MaxVotesPerVoter::get(),
+				Error::<T>::TooManyActiveVotes
+			);
+
+			let vote_amount = vote_decision.amount();
+
+			ensure!(vote_amount > 0, Error::<T>::InvalidVoteAmount);
+			ensure!(vote_amount >= T::MinVoteAmount::get(), Error::<T>::VoteAmountTooLow);
+			ensure!(
+				vote_amount <= Self::proposal_vote_limit(&proposal),
+				Error::<T>::VoteAmountLimit
+			);
+
+			//Reserve balance corresponding to vote amount^2.
+			let amount_to_reserve: BalanceOf<T> =
+				(vote_amount).checked_pow(2).ok_or(Error::<T>::Overflow)?.into();
+
+			let multiplier = Self::voting_multiplier(&who).saturating_mul(conviction.multiplier());
+			let (quadratic_ayes, quadratic_nays) =
+				Self::quadratic_power(&vote_decision, multiplier);
+			let vote = Vote {
+				vote_decision: vote_decision.clone(),
+				locked: true,
+				multiplier,
+				conviction,
+				quadratic_ayes,
+				quadratic_nays,
+				reserved: amount_to_reserve,
+			};
+			Self::add_to_tally(&mut proposal, &vote)?;
+			ensure!(
+				Self::decisive_points(&proposal) <= T::MaxProposalVotes::get(),
+				Error::<T>::ProposalVoteCapReached
+			);
+
+			ensure!(
+				T::Currency::can_reserve(&who, amount_to_reserve),
+				Error::<T>::InsufficientBalanceForVote
+			);
+			Self::reserve_for_vote(&who, amount_to_reserve)?;
+
+			//Insert vote and update proposals
+			<VotersByProposal<T>>::try_mutate(proposal_id, |voters| {
+				voters.get_or_insert_with(Default::default).try_push(who.clone())
+			})
+			.map_err(|_| Error::<T>::TooManyVotersOnProposal)?;
+			<Votes<T>>::insert(who.clone(), proposal_id, vote.clone());
+			<CanceledVotes<T>>::remove(proposal_id, &who);
+			<Proposals<T>>::insert(proposal_id, proposal);
+			<VoteCount<T>>::mutate(proposal_id, |count| {
+				*count = Some(count.unwrap_or_default().saturating_add(1))
+			});
+			<ActiveVotes<T>>::mutate(&who, |count| {
+				*count = Some(count.unwrap_or_default().saturating_add(1))
+			});
+
+			Self::deposit_event(Event::VoteCasted { proposal_id, who, vote_decision });
+			Ok(())
+		}
+		/// Replaces the vote of `who` on the proposal, moving its points between the tallies and
+		/// reserving or unreserving the balance difference.
+		fn do_update_vote(
+			who: T::AccountId,
+			proposal_id: ProposalId,
+			new_vote_decision: VoteDecision,
+		) -> DispatchResult {
+			ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
+			//Verify voter is part of register voters and vote exists
+			ensure!(Self::is_registered(&who), Error::<T>::VoterIsNotRegistered);
+
+			let mut proposal =
+				Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(Self::is_proposal_active(&proposal), Error::<T>::ProposalAlreadyEnded);
+			ensure!(Self::can_vote_on(&who, &proposal), Error::<T>::ProposerCannotVote);
+
+			//Get vote and verify if it exists
+			let current_vote =
+				<Votes<T>>::try_get(&who, &proposal_id).ok().ok_or(Error::<T>::VoteNotFound)?;
+
+			let current_amount: u32 = current_vote.vote_decision.amount();
+
+			let new_amount = new_vote_decision.amount();
+			if new_amount.cmp(&current_amount) == Ordering::Less {
+				//Check threshold
+				ensure!(
+					!Self::passed_removal_threshold(&proposal),
+					Error::<T>::PassedRemovalThreshold
+				);
+			}
+
+			ensure!(new_amount != 0, Error::<T>::InvalidUpdateAmount);
+			ensure!(new_amount >= T::MinVoteAmount::get(), Error::<T>::VoteAmountTooLow);
+			ensure!(
+				new_amount <= Self::proposal_vote_limit(&proposal),
+				Error::<T>::VoteAmountLimit
+			);
+
+			let new_reserved: BalanceOf<T> =
+				new_amount.checked_pow(2).ok_or(Error::<T>::Overflow)?.into();
+			let multiplier =
+				Self::voting_multiplier(&who).saturating_mul(current_vote.conviction.multiplier());
+			let (quadratic_ayes, quadratic_nays) =
+				Self::quadratic_power(&new_vote_decision, multiplier);
+			let new_vote = Vote {
+				vote_decision: new_vote_decision,
+				locked: true,
+				multiplier,
+				conviction: current_vote.conviction,
+				quadratic_ayes,
+				quadratic_nays,
+				reserved: new_reserved,
+			};
+			let previous_points = Self::decisive_points(&proposal);
+			Self::remove_from_tally(&mut proposal, &current_vote)?;
+			Self::add_to_tally(&mut proposal, &new_vote)?;
+
+			//Only the points added on top of the current ones count towards the cap.
+			let new_points = Self::decisive_points(&proposal);
+			ensure!(
+				new_points <= previous_points || new_points <= T::MaxProposalVotes::get(),
+				Error::<T>::ProposalVoteCapReached
+			);
+
+			//Modify reserved amount, relative to what the current vote reserved.
+			match new_reserved.cmp(&current_vote.reserved) {
+				Ordering::Greater => {
+					Self::reserve_for_vote(
+						&who,
+						new_reserved.saturating_sub(current_vote.reserved),
+					)?;
+				},
+				Ordering::Less => {
+					Self::unreserve_for_vote(
+						&who,
+						current_vote.reserved.saturating_sub(new_reserved),
+					);
+				},
+				_ => (),
+			};
+
+			<Votes<T>>::insert(who.clone(), proposal_id, new_vote.clone());
+			<Proposals<T>>::insert(proposal_id, proposal);
+			Self::deposit_event(Event::VoteUpdated {
+				proposal_id,
+				who,
+				amount_delta: i64::from(new_amount) - i64::from(current_amount),
+				direction_changed: current_vote.vote_decision.direction() !=
+					new_vote.vote_decision.direction(),
+				previous: current_vote.vote_decision,
+				new: new_vote.vote_decision,
+			});
+
+			Ok(())
+		}
+		/// Adds `who` to the registered voters and checks the remaining voter capacity.
+		fn do_register_voter(who: T::AccountId) -> DispatchResult {
+			let total = Self::insert_voter(who)?;
+			Self::check_voter_capacity(total);
+			Ok(())
+		}
+		/// Adds `who` to the registered voters and increases the voter counter along with it, so
+		/// `AmountVoters` never counts an account that has no `RegisteredVoters` entry. Returns
+		/// the new number of registered voters.
+		fn insert_voter(who: T::AccountId) -> Result<u32, DispatchError> {
+			ensure!(!Self::is_registered(&who), Error::<T>::AlreadyRegistered);
+
+			let total = <AmountVoters<T>>::get().unwrap_or_default().saturating_add(1);
+			<RegisteredVoters<T>>::insert(who.clone(), ());
+			<AmountVoters<T>>::put(total);
+
+			Self::deposit_event(Event::VoterRegistered { who, total });
+			Ok(total)
+		}
+		/// Emits a `VoterCapacityWarning` when the free voter slots left by `total` registered
+		/// voters drop below `CapacityWarnThreshold`. The warning is emitted once per crossing and
+		/// rearmed once enough voters are unregistered to go back over the threshold.
+		fn check_voter_capacity(total: u32) {
+			let remaining = Self::max_voters().saturating_sub(total);
+			let below_threshold = remaining < T::CapacityWarnThreshold::get();
+			if below_threshold == <CapacityWarningIssued<T>>::get() {
+				return
+			}
+
+			<CapacityWarningIssued<T>>::put(below_threshold);
+			if below_threshold {
+				Self::deposit_event(Event::VoterCapacityWarning { remaining });
+			}
+		}
+		/// Sets the final status of the proposal from its current tallies.
+		///
+		/// Voters are notified with a single `ProposalSettled` event rather than one event each:
+		/// the turnout counter is already read to check the quorum, while listing the voters
+		/// would cost a read growing with `MaxVotersPerProposal`. Clients can fetch the voters
+		/// with `voters_for` when needed.
+		fn do_finish_proposal(mut proposal: Proposal<T>) {
+			let proposal_id = proposal.id;
+			let voting_result: ProposalStatus = Self::project_status(&proposal);
+
+			Self::set_status(&mut proposal, voting_result);
+			let slash = match voting_result {
+				ProposalStatus::NoQuorum => true,
+				//Nobody voted on an expired proposal, so it missed any quorum required as well.
+				ProposalStatus::Expired => Self::missed_quorum(&proposal),
+				_ => false,
+			};
+			if slash {
+				Self::slash_deposit(&proposal);
+			} else {
+				Self::refund_deposit(&proposal);
+			}
+
+			Self::deposit_event(Event::VotesTallied {
+				proposal_id,
+				ayes: proposal.tally.ayes,
+				nays: proposal.tally.nays,
+				abstains: proposal.tally.abstains,
+			});
+			let proposer_still_registered = Self::is_registered(&proposal.proposer);
+			<Proposals<T>>::insert(proposal_id, proposal);
+			Self::deposit_event(Event::ProposalEnded {
+				proposal_id,
+				status: voting_result,
+				proposer_still_registered,
+			});
+			let affected_voters = Self::voter_turnout(&proposal_id);
+			if affected_voters > 0 {
+				Self::deposit_event(Event::ProposalSettled { proposal_id, affected_voters });
+			}
+
+			if let Some(call) = <EnactmentCalls<T>>::take(proposal_id) {
+				if voting_result == ProposalStatus::Passed {
+					Self::enact(proposal_id, call);
+				}
+			}
+		}
+		/// Dispatches the call carried by a passed proposal with the `EnactmentOrigin`.
+		fn enact(proposal_id: ProposalId, call: BoundedVec<u8, T::MaxCallSize>) {
+			let result = <T as Config>::RuntimeCall::decode(&mut &call[..])
+				.map_err(|_| Error::<T>::UndecodableCall.into())
+				.and_then(|call| {
+					call.dispatch(T::EnactmentOrigin::get()).map(|_| ()).map_err(|e| e.error)
+				});
+			Self::deposit_event(Event::ProposalEnacted { proposal_id, result });
+		}
+		/// Returns the deposit of a proposal that is no longer in progress to its proposer.
+		fn refund_deposit(proposal: &Proposal<T>) {
+			if proposal.deposit.is_zero() {
+				return
+			}
+			T::Currency::unreserve_named(&Self::reserve_id(), &proposal.proposer, proposal.deposit);
+			Self::deposit_event(Event::ProposalDepositRefunded {
+				proposal_id: proposal.id,
+				who: proposal.proposer.clone(),
+				amount: proposal.deposit,
+			});
+		}
+		/// Slashes the deposit of a proposal that ended without quorum, which includes proposals
+		/// that expired without votes while a quorum is required.
+		fn slash_deposit(proposal: &Proposal<T>) {
+			if proposal.deposit.is_zero() {
+				return
+			}
+			let (imbalance, _) = T::Currency::slash_reserved_named(
+				&Self::reserve_id(),
+				&proposal.proposer,
+				proposal.deposit,
+			);
+			T::SlashHandler::on_unbalanced(imbalance);
+			Self::deposit_event(Event::ProposalDepositSlashed {
+				proposal_id: proposal.id,
+				who: proposal.proposer.clone(),
+				amount: proposal.deposit,
+			});
+		}
+		/// Unreserves the balance locked by the vote of `who` on a finished proposal. Nothing is
+		/// written unless every check passes.
+		fn do_unlock_balance(who: &T::AccountId, proposal_id: ProposalId) -> DispatchResult {
+			let proposal: Proposal<T> =
+				Self::get_proposal(&proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+
+			//A canceled vote got its balance back already, whatever the proposal status.
+			let mut vote = match <Votes<T>>::get(who, proposal_id) {
+				Some(vote) => vote,
+				None if <CanceledVotes<T>>::contains_key(proposal_id, who) =>
+					return Err(Error::<T>::AlreadyRefunded.into()),
+				None => return Err(Error::<T>::VoteNotFound.into()),
+			};
+			ensure!(proposal.status != ProposalStatus::InProgress, Error::<T>::ProposalInProgress);
+			ensure!(vote.locked, Error::<T>::BalanceAlreadyUnocked);
+			//Votes on canceled proposals are released regardless of their conviction.
+			if vote.conviction != Conviction::None && proposal.status != ProposalStatus::Canceled {
+				ensure!(
+					<frame_system::Pallet<T>>::block_number() >=
+						Self::conviction_unlock_block(&proposal, &vote),
+					Error::<T>::ConvictionLocked
+				);
+			}
+			if proposal.status != ProposalStatus::Canceled {
+				ensure!(
+					<frame_system::Pallet<T>>::block_number() >=
+						Self::post_result_unlock_block(&proposal),
+					Error::<T>::StillLocked
+				);
+			}
+
+			let amount_to_unreserve = vote.reserved;
+			vote.locked = false;
+			<Votes<T>>::insert(who.clone(), proposal_id, vote);
+			Self::release_active_vote(who);
+			Self::unreserve_for_vote(who, amount_to_unreserve);
+			Ok(())
+		}
+		/// Reserves `amount` from `who` for one of their votes, adding it to `TotalReserved`.
+		fn reserve_for_vote(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			T::Currency::reserve_named(&Self::reserve_id(), who, amount)?;
+			<TotalReserved<T>>::mutate(|total| *total = total.saturating_add(amount));
+			Ok(())
+		}
+		/// Returns `amount` reserved for one of the votes of `who`, removing it from
+		/// `TotalReserved`.
+		fn unreserve_for_vote(who: &T::AccountId, amount: BalanceOf<T>) {
+			T::Currency::unreserve_named(&Self::reserve_id(), who, amount);
+			<TotalReserved<T>>::mutate(|total| *total = total.saturating_sub(amount));
+		}
+		/// Frees one of the locked votes counted against `MaxVotesPerVoter` for `who`.
+		fn release_active_vote(who: &T::AccountId) {
+			<ActiveVotes<T>>::mutate_exists(who, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0)
+			});
+		}
+		/// Moves the proposal to `status`, keeping the per status counters and the active
+		/// proposal ids in sync. Listing a proposal can fail, so callers putting a proposal back
+		/// in progress must list it with `index_active` and `index_tags` themselves.
+		fn set_status(proposal: &mut Proposal<T>, status: ProposalStatus) {
+			if proposal.status != ProposalStatus::InProgress && status == ProposalStatus::InProgress
+			{
+				<ActiveProposalCount<T>>::mutate(|count| {
+					*count = Some(count.unwrap_or_default().saturating_add(1))
+				});
+				proposal.ended_at = None;
+				Self::schedule_expiry(proposal.time_period);
+			}
+			if proposal.status == ProposalStatus::InProgress && status != ProposalStatus::InProgress
+			{
+				<ActiveProposalCount<T>>::mutate(|count| {
+					*count = Some(count.unwrap_or_default().saturating_sub(1))
+				});
+				Self::unindex_active(proposal.id);
+				Self::unindex_tags(proposal.id, &proposal.tags);
+				proposal.ended_at = Some(<frame_system::Pallet<T>>::block_number());
+				Self::unschedule_expiry(proposal.time_period);
+			}
+			Self::decrease_status_count(&proposal.status);
+			Self::increase_status_count(&status);
+			proposal.status = status;
+		}
+		/// Lists a proposal in progress ending at block `end` in `ActiveProposalIds`, keeping the
+		/// list ordered by end block. Fails if `MaxProposals` proposals are listed already.
+		pub(crate) fn index_active(
+			proposal_id: ProposalId,
+			end: BlockNumberFor<T>,
+		) -> DispatchResult {
+			<ActiveProposalIds<T>>::try_mutate(|ids| {
+				let entry = (end, proposal_id);
+				let index = ids.binary_search(&entry).unwrap_or_else(|index| index);
+				ids.try_insert(index, entry).map_err(|_| Error::<T>::TooManyProposals.into())
+			})
+		}
+		/// Removes a proposal that is no longer in progress from `ActiveProposalIds`.
+		fn unindex_active(proposal_id: ProposalId) {
+			<ActiveProposalIds<T>>::mutate(|ids| ids.retain(|(_, id)| *id != proposal_id));
+		}
+		/// Lists a proposal in progress under each of its tags in `ProposalsByTag`.
+		fn index_tags(
+			proposal_id: ProposalId,
+			tags: &BoundedVec<TagOf<T>, T::MaxTags>,
+		) -> DispatchResult {
+			for tag in tags.iter() {
+				<ProposalsByTag<T>>::try_mutate(tag, |proposals| {
+					let ids = proposals.get_or_insert_with(Default::default);
+					if ids.contains(&proposal_id) {
+						return Ok(())
+					}
+					ids.try_push(proposal_id)
+				})
+				.map_err(|_| Error::<T>::TooManyProposalsWithTag)?;
+			}
+			Ok(())
+		}
+		/// Removes a proposal that is no longer in progress from the lists of its tags.
+		fn unindex_tags(proposal_id: ProposalId, tags: &BoundedVec<TagOf<T>, T::MaxTags>) {
+			for tag in tags.iter() {
+				<ProposalsByTag<T>>::mutate_exists(tag, |proposals| {
+					if let Some(ids) = proposals {
+						ids.retain(|id| *id != proposal_id);
+						if ids.is_empty() {
+							*proposals = None;
+						}
+					}
+				});
+			}
+		}
+		/// Counts a proposal in progress reaching its end at block `end`.
+		fn schedule_expiry(end: BlockNumberFor<T>) {
+			<ExpiringAt<T>>::mutate(end, |count| {
+				*count = Some(count.unwrap_or_default().saturating_add(1))
+			});
+		}
+		/// Stops counting a proposal that no longer ends at block `end`. Expiries of past blocks
+		/// were already removed by `on_finalize`.
+		fn unschedule_expiry(end: BlockNumberFor<T>) {
+			if end < <frame_system::Pallet<T>>::block_number() {
+				return
+			}
+			<ExpiringAt<T>>::mutate_exists(end, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0)
+			});
+		}
+		fn increase_status_count(status: &ProposalStatus) {
+			<StatusCounts<T>>::mutate(status, |count| {
+				*count = Some(count.unwrap_or_default().saturating_add(1))
+			});
+		}
+		fn decrease_status_count(status: &ProposalStatus) {
+			<StatusCounts<T>>::mutate(status, |count| {
+				*count = Some(count.unwrap_or_default().saturating_sub(1))
+			});
+		}
+		/// Adds the points of `vote`, scaled by its multiplier, to the proposal tallies.
+		fn add_to_tally(proposal: &mut Proposal<T>, vote: &Vote<BalanceOf<T>>) -> DispatchResult {
+			proposal
+				.tally
+				.add(&vote.vote_decision, vote.multiplier)
+				.map_err(|_| Error::<T>::Overflow)?;
+			proposal.quadratic_ayes = proposal.quadratic_ayes.saturating_add(vote.quadratic_ayes);
+			proposal.quadratic_nays = proposal.quadratic_nays.saturating_add(vote.quadratic_nays);
+			Ok(())
+		}
+		/// Quadratic voting power of a vote in favor of and against the proposal, used instead of
+		/// the raw points to decide proposals when `UseQuadraticTally` is set.
+		///
+		/// Each side gets `isqrt(points) * multiplier`, where `isqrt` is the integer square root
+		/// of `sp_arithmetic`, which always rounds down: 3 points give a power of 1. The result is
+		/// stored in the vote so that removing it subtracts exactly the power it added.
+		pub fn quadratic_power(vote_decision: &VoteDecision, multiplier: u32) -> (u32, u32) {
+			let (ayes, nays) = match *vote_decision {
+				VoteDecision::Aye(v) => (v, 0),
+				VoteDecision::Nay(v) => (0, v),
+				VoteDecision::Abstain(_) => (0, 0),
+				VoteDecision::Split { aye, nay } => (aye, nay),
+			};
+			(
+				ayes.integer_sqrt().saturating_mul(multiplier),
+				nays.integer_sqrt().saturating_mul(multiplier),
+			)
+		}
+		/// Sum of the aye and nay points of the proposal, counted against `MaxProposalVotes`.
+		fn decisive_points(proposal: &Proposal<T>) -> u32 {
+			proposal.tally.ayes.saturating_add(proposal.tally.nays)
+		}
+		/// Removes the points previously added by `vote` from the proposal tallies.
+		fn remove_from_tally(
+			proposal: &mut Proposal<T>,
+			vote: &Vote<BalanceOf<T>>,
+		) -> DispatchResult {
+			proposal
+				.tally
+				.sub(&vote.vote_decision, vote.multiplier)
+				.map_err(|_| Error::<T>::Overflow)?;
+			proposal.quadratic_ayes = proposal.quadratic_ayes.saturating_sub(vote.quadratic_ayes);
+			proposal.quadratic_nays = proposal.quadratic_nays.saturating_sub(vote.quadratic_nays);
+			Ok(())
+		}
+		/// Checks the invariants of the pallet storage:
+		///
+		/// - `AmountVoters` equals the number of `RegisteredVoters` entries.
+		/// - Every proposal is stored under its own `id`.
+		/// - `ActiveProposalCount` equals the number of proposals in progress.
+		/// - `ActiveVotes` of every account equals the number of its locked votes.
+		/// - `TotalReserved` equals the balance reserved for every locked vote.
+		/// - Every account has at least the balance implied by its locked votes (the square of
+		///   their points) and the deposits of its proposals in progress reserved. Other pallets
+		///   may reserve on top of it, so the reserved balance is not required to match exactly.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), DispatchError> {
+			let registered = <RegisteredVoters<T>>::iter_keys().count() as u32;
+			ensure!(
+				<AmountVoters<T>>::get().unwrap_or_default() == registered,
+				"AmountVoters does not match the number of registered voters"
+			);
+
+			let mut implied_reserves: sp_std::collections::btree_map::BTreeMap<
+				T::AccountId,
+				BalanceOf<T>,
+			> = Default::default();
+			let mut active_proposals: u32 = 0;
+			let active_ids = <ActiveProposalIds<T>>::get();
+			for (proposal_id, proposal) in <Proposals<T>>::iter() {
+				ensure!(proposal.id == proposal_id, "Proposal stored under a different id");
+				if proposal.status == ProposalStatus::InProgress {
+					ensure!(
+						active_ids.contains(&(proposal.time_period, proposal_id)),
+						"Proposal in progress missing from ActiveProposalIds"
+					);
+					active_proposals = active_proposals.saturating_add(1);
+					let total = implied_reserves.entry(proposal.proposer).or_default();
+					*total = total.saturating_add(proposal.deposit);
+				}
+			}
+			ensure!(
+				<ActiveProposalCount<T>>::get().unwrap_or_default() == active_proposals,
+				"ActiveProposalCount does not match the number of proposals in progress"
+			);
+			ensure!(
+				active_ids.len() as u32 == active_proposals,
+				"ActiveProposalIds does not match the proposals in progress"
+			);
+			ensure!(
+				active_ids.windows(2).all(|pair| pair[0] < pair[1]),
+				"ActiveProposalIds is not ordered by end block"
+			);
+
+			let mut active_votes: sp_std::collections::btree_map::BTreeMap<T::AccountId, u32> =
+				Default::default();
+			let mut vote_reserves: BalanceOf<T> = Zero::zero();
+			for (who, _, vote) in <Votes<T>>::iter() {
+				if !vote.locked {
+					continue
+				}
+				vote_reserves = vote_reserves.saturating_add(vote.reserved);
+				let count = active_votes.entry(who.clone()).or_default();
+				*count = count.saturating_add(1);
+				let total = implied_reserves.entry(who).or_default();
+				*total = total.saturating_add(vote.reserved);
+			}
+			ensure!(
+				<ActiveVotes<T>>::iter().count() == active_votes.len(),
+				"ActiveVotes counts accounts without locked votes"
+			);
+			ensure!(
+				Self::total_locked() == vote_reserves,
+				"TotalReserved does not match the balance reserved for the locked votes"
+			);
+			for (who, count) in active_votes {
+				ensure!(
+					Self::active_votes_of(&who) == count,
+					"ActiveVotes does not match the number of locked votes"
+				);
+			}
+			for (who, deposit) in <MembershipDeposits<T>>::iter() {
+				let total = implied_reserves.entry(who).or_default();
+				*total = total.saturating_add(deposit);
+			}
+			for (_, who, (_, deposit)) in <Commitments<T>>::iter() {
+				let total = implied_reserves.entry(who).or_default();
+				*total = total.saturating_add(deposit);
+			}
+			for (who, implied) in implied_reserves {
+				ensure!(
+					T::Currency::reserved_balance_named(&Self::reserve_id(), &who) >= implied,
+					"Reserved balance is lower than the one implied by the votes"
+				);
+			}
+
+			Ok(())
+		}
+		/// Whether the proposal is inside its removal threshold, its own one if it has any or the
+		/// global `VoteRemovalThreshold` otherwise. Proposals that already reached their end are
+		/// always considered past the threshold.
+		pub fn passed_removal_threshold(proposal: &Proposal<T>) -> bool {
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			if current_block_number >= proposal.time_period {
+				return true
+			}
 
-			let difference = *end_time_period - current_block_number;
-			difference < T::VoteRemovalThreshold::get().into()
+			let threshold = proposal.removal_threshold.unwrap_or_else(Self::removal_threshold);
+			let difference = proposal.time_period - current_block_number;
+			difference < threshold.into()
 		}
 	}
 }
@@ -0,0 +1,207 @@
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+	traits::{NamedReservableCurrency, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+use crate::{
+	ActiveProposalCount, ActiveVotes, BalanceOf, Config, Conviction, ExpiringAt, Pallet, Proposal,
+	ProposalStatus, Proposals, ProposalsByProposer, StatusCounts, Tally, TotalReserved, Vote,
+	VoteCount, VoteDecision, VotersByProposal, Votes,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// Storage layout of the pallet before the storage version was introduced.
+	pub(crate) mod v0 {
+		use super::*;
+		use frame_system::pallet_prelude::BlockNumberFor;
+
+		#[derive(Encode, Decode)]
+		pub struct OldProposal<T: Config> {
+			pub id: crate::ProposalId,
+			pub proposer: T::AccountId,
+			pub text: T::Hash,
+			pub time_period: BlockNumberFor<T>,
+			pub status: ProposalStatus,
+			pub ayes: u32,
+			pub nays: u32,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct OldVote {
+			pub vote_decision: VoteDecision,
+			pub locked: bool,
+		}
+	}
+
+	/// Migrates `Proposals` and `Votes` to the layout of storage version 1.
+	///
+	/// Proposals get no abstains, no deposit (none was reserved for them), no minimum turnout, no
+	/// vote limit, no call, no tags, no `ended_at`, no removal threshold and a `created_at` of
+	/// block zero, as their real creation and end blocks are unknown. Their quadratic tallies are
+	/// rebuilt from the quadratic power of their votes. Votes get a multiplier of one and no
+	/// conviction, as neither delegation nor convictions existed when they were casted, and
+	/// store the square of their amount as their reserved balance. Locked votes move that
+	/// balance from the anonymous reserve they used to the named reserve of the pallet, so it can
+	/// be unlocked like any other vote. If the named reserve fails the balance is left free and
+	/// the vote keeps no reserved balance, so it isn't counted in `TotalReserved`. The status,
+	/// active proposals, expiries, active votes, total reserved, turnout, voters, active
+	/// proposals and proposer indexes are rebuilt from the migrated entries.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut proposals: u64 = 0;
+			Proposals::<T>::translate::<v0::OldProposal<T>, _>(|_, old| {
+				proposals.saturating_inc();
+				StatusCounts::<T>::mutate(&old.status, |count| {
+					*count = Some(count.unwrap_or_default().saturating_add(1))
+				});
+				let _ = ProposalsByProposer::<T>::try_mutate(&old.proposer, |ids| {
+					ids.get_or_insert_with(Default::default).try_push(old.id)
+				});
+				if old.status == ProposalStatus::InProgress {
+					ActiveProposalCount::<T>::mutate(|count| {
+						*count = Some(count.unwrap_or_default().saturating_add(1))
+					});
+					let _ = Pallet::<T>::index_active(old.id, old.time_period);
+					if old.time_period >= frame_system::Pallet::<T>::block_number() {
+						ExpiringAt::<T>::mutate(old.time_period, |count| {
+							*count = Some(count.unwrap_or_default().saturating_add(1))
+						});
+					}
+				}
+				Some(Proposal {
+					id: old.id,
+					proposer: old.proposer,
+					text: old.text,
+					time_period: old.time_period,
+					status: old.status,
+					tally: Tally { ayes: old.ayes, nays: old.nays, abstains: 0 },
+					deposit: Zero::zero(),
+					created_at: Zero::zero(),
+					quadratic_ayes: 0,
+					quadratic_nays: 0,
+					min_turnout: None,
+					per_voter_limit: None,
+					call_hash: None,
+					tags: Default::default(),
+					ended_at: None,
+					removal_threshold: None,
+				})
+			});
+
+			let mut votes: u64 = 0;
+			let mut failed_reserves: u32 = 0;
+			Votes::<T>::translate::<v0::OldVote, _>(|who, proposal_id, old| {
+				votes.saturating_inc();
+				VoteCount::<T>::mutate(proposal_id, |count| {
+					*count = Some(count.unwrap_or_default().saturating_add(1))
+				});
+				let amount = old.vote_decision.amount();
+				let mut reserved: BalanceOf<T> = amount.saturating_mul(amount).into();
+				if old.locked {
+					reserved = reserved.saturating_sub(T::Currency::unreserve(&who, reserved));
+					if T::Currency::reserve_named(&Pallet::<T>::reserve_id(), &who, reserved)
+						.is_err()
+					{
+						failed_reserves.saturating_inc();
+						reserved = Zero::zero();
+					}
+					ActiveVotes::<T>::mutate(&who, |count| {
+						*count = Some(count.unwrap_or_default().saturating_add(1))
+					});
+					TotalReserved::<T>::mutate(|total| *total = total.saturating_add(reserved));
+				}
+				let _ = VotersByProposal::<T>::try_mutate(proposal_id, |voters| {
+					voters.get_or_insert_with(Default::default).try_push(who)
+				});
+				let (quadratic_ayes, quadratic_nays) =
+					Pallet::<T>::quadratic_power(&old.vote_decision, 1);
+				let vote = Vote {
+					vote_decision: old.vote_decision,
+					locked: old.locked,
+					multiplier: 1,
+					conviction: Conviction::None,
+					quadratic_ayes,
+					quadratic_nays,
+					reserved,
+				};
+				Proposals::<T>::mutate(proposal_id, |proposal| {
+					if let Some(p) = proposal.as_mut() {
+						p.quadratic_ayes = p.quadratic_ayes.saturating_add(quadratic_ayes);
+						p.quadratic_nays = p.quadratic_nays.saturating_add(quadratic_nays);
+					}
+				});
+				Some(vote)
+			});
+
+			if failed_reserves > 0 {
+				log::warn!(
+					target: crate::LOG_TARGET,
+					"{} locked votes could not move their balance to the named reserve and were \
+					 left without reserved balance",
+					failed_reserves,
+				);
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			// Every proposal also updates its status, active and expiry counters and the active
+			// ids, and every vote its turnout, voters index, active votes counter, total reserved,
+			// proposal, account and named reserves.
+			let touched = proposals
+				.saturating_mul(5)
+				.saturating_add(votes.saturating_mul(8))
+				.saturating_add(1);
+			T::DbWeight::get().reads_writes(touched, touched)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let proposals = Proposals::<T>::iter_keys().count() as u32;
+			let votes = Votes::<T>::iter_keys().count() as u32;
+			Ok((proposals, votes).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (proposals, votes): (u32, u32) = Decode::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre upgrade state")?;
+
+			ensure!(Pallet::<T>::storage_version() == 1, "Storage version was not updated");
+			ensure!(
+				Proposals::<T>::iter().count() as u32 == proposals,
+				"Proposals were lost during the migration"
+			);
+			ensure!(
+				Votes::<T>::iter().count() as u32 == votes,
+				"Votes were lost during the migration"
+			);
+			let reserved = Votes::<T>::iter_values()
+				.filter(|vote| vote.locked)
+				.fold(BalanceOf::<T>::zero(), |total, vote| total.saturating_add(vote.reserved));
+			ensure!(
+				TotalReserved::<T>::get() == reserved,
+				"TotalReserved doesn't match the balance reserved by the migrated votes"
+			);
+			for (who, _, vote) in Votes::<T>::iter().filter(|(_, _, vote)| vote.locked) {
+				ensure!(
+					T::Currency::reserved_balance_named(&Pallet::<T>::reserve_id(), &who) >=
+						vote.reserved,
+					"A migrated vote has more reserved balance than its account"
+				);
+			}
+			Ok(())
+		}
+	}
+}
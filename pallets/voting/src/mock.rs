@@ -2,6 +2,7 @@ use crate as pallet_voting;
 use frame_support::{
 	parameter_types,
 	traits::{ConstU16, ConstU64},
+	PalletId,
 };
 use sp_core::H256;
 use sp_runtime::{
@@ -56,9 +57,39 @@ impl frame_system::Config for Test {
 parameter_types! {
 	pub const ExistentialDeposit: u64 = 1;
 	pub const MaxLocks: u32 = 10;
+	pub const MaxReserves: u32 = 10;
 	pub static MaxVoters: u32 = 100;
 	pub static VoteRemovalThreshold: u32 = 20;
 	pub const VoteLimit: u32 = 7;
+	pub static MinVoteAmount: u32 = 1;
+	pub const MaxBatchSize: u32 = 10;
+	pub static MaxProposalVotes: u32 = 1000;
+	pub static MaxVotersPerProposal: u32 = 100;
+	pub static Quorum: u32 = 0;
+	pub static ProposalCooldown: u64 = 0;
+	pub static ProposalDeposit: u128 = 0;
+	pub static MaxProposalDuration: u64 = 1000;
+	pub static UseQuadraticTally: bool = false;
+	pub static RejectTies: bool = false;
+	pub static SnapshotInterval: u64 = 0;
+	pub static MaxSnapshotsPerBlock: u32 = 10;
+	pub static ConvictionPeriod: u64 = 10;
+	pub static PostResultLock: u64 = 0;
+	pub static RevealPeriod: u64 = 10;
+	pub static CommitmentDeposit: u128 = 1;
+	pub static MaxExpiriesPerBlock: u32 = 10;
+	pub static ProposerCanVote: bool = true;
+	pub static MembershipDeposit: u128 = 5;
+	pub static MaxDelegationsPerAccount: u32 = 10;
+	pub static MinProposalDuration: u64 = 1;
+	pub static CapacityWarnThreshold: u32 = 0;
+	pub static MaxProposals: u32 = 100;
+	pub static MaxProposalsPerProposer: u32 = 20;
+	pub static MaxVotesPerVoter: u32 = 20;
+	pub static MaxCallSize: u32 = 128;
+	pub static MaxTags: u32 = 3;
+	pub static MaxTagLen: u32 = 16;
+	pub const VotingPalletId: PalletId = PalletId(*b"py/votin");
 }
 
 impl pallet_balances::Config for Test {
@@ -69,19 +100,74 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 	type WeightInfo = ();
 	type MaxLocks = MaxLocks;
-	type MaxReserves = ();
+	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
 }
 
 impl pallet_voting::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type PalletId = VotingPalletId;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+	type RuntimeCall = RuntimeCall;
+	type EnactmentOrigin = EnactmentOrigin;
 	type MaxVoters = MaxVoters;
 	type VoteRemovalThreshold = VoteRemovalThreshold;
 	type VoteLimit = VoteLimit;
+	type MinVoteAmount = MinVoteAmount;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxProposalVotes = MaxProposalVotes;
+	type MaxVotersPerProposal = MaxVotersPerProposal;
+	type Quorum = Quorum;
+	type ProposalCooldown = ProposalCooldown;
+	type ProposalDeposit = ProposalDeposit;
+	type MaxProposalDuration = MaxProposalDuration;
+	type MaxProposals = MaxProposals;
+	type MaxProposalsPerProposer = MaxProposalsPerProposer;
+	type MaxVotesPerVoter = MaxVotesPerVoter;
+	type MaxCallSize = MaxCallSize;
+	type MaxTags = MaxTags;
+	type MaxTagLen = MaxTagLen;
+	type UseQuadraticTally = UseQuadraticTally;
+	type TieBreaker = TieBreaker;
+	type SnapshotInterval = SnapshotInterval;
+	type MaxSnapshotsPerBlock = MaxSnapshotsPerBlock;
+	type ConvictionPeriod = ConvictionPeriod;
+	type PostResultLock = PostResultLock;
+	type RevealPeriod = RevealPeriod;
+	type CommitmentDeposit = CommitmentDeposit;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type ProposerCanVote = ProposerCanVote;
+	type MembershipDeposit = MembershipDeposit;
+	type MaxDelegationsPerAccount = MaxDelegationsPerAccount;
+	type MinProposalDuration = MinProposalDuration;
+	type CapacityWarnThreshold = CapacityWarnThreshold;
+	type SlashHandler = ();
 	type WeightInfo = ();
 }
 
+/// Tie breaker switching between the shipped strategies through `RejectTies`.
+pub struct TieBreaker;
+
+impl pallet_voting::TieBreakStrategy for TieBreaker {
+	fn resolve_tie() -> pallet_voting::ProposalStatus {
+		if RejectTies::get() {
+			pallet_voting::AlwaysReject::resolve_tie()
+		} else {
+			pallet_voting::AlwaysTie::resolve_tie()
+		}
+	}
+}
+
+/// Dispatches the calls of passed proposals as root.
+pub struct EnactmentOrigin;
+
+impl frame_support::traits::Get<RuntimeOrigin> for EnactmentOrigin {
+	fn get() -> RuntimeOrigin {
+		RuntimeOrigin::root()
+	}
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	frame_system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
@@ -13,7 +13,7 @@ mod register_voter {
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
 			assert!(Voting::is_registered(&2));
 			assert!(System::events().len() == 1);
-			System::assert_has_event(Event::VoterRegistered { who: 2 }.into());
+			System::assert_has_event(Event::VoterRegistered { who: 2, total: 1 }.into());
 
 			//Try to re-register the same voter;
 			assert_noop!(
@@ -23,6 +23,17 @@ mod register_voter {
 		});
 	}
 
+	#[test]
+	fn voter_count() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Voting::voter_count(), 0);
+			for voter in 1..=3 {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_eq!(Voting::voter_count(), 3);
+		});
+	}
+
 	#[test]
 	fn register_invalid_origin() {
 		new_test_ext().execute_with(|| {
@@ -33,6 +44,18 @@ mod register_voter {
 		});
 	}
 
+	#[test]
+	fn signed_origin_cant_self_register() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Voting::register_voter(RuntimeOrigin::signed(1), 1),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert!(!Voting::is_registered(&1));
+			assert_eq!(Voting::voter_count(), 0);
+		});
+	}
+
 	#[test]
 	fn reached_max_voters() {
 		new_test_ext().execute_with(|| {
@@ -46,6 +69,67 @@ mod register_voter {
 	}
 }
 
+mod batch_register_voters {
+	use super::*;
+	use frame_support::BoundedVec;
+
+	fn batch(voters: Vec<u64>) -> BoundedVec<u64, MaxBatchSize> {
+		BoundedVec::try_from(voters).unwrap()
+	}
+
+	#[test]
+	fn registers_all_voters() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Voting::batch_register_voters(RuntimeOrigin::root(), batch(vec![1, 2, 3])));
+			for voter in 1..=3 {
+				assert!(Voting::is_registered(&voter));
+				System::assert_has_event(
+					Event::VoterRegistered { who: voter, total: voter as u32 }.into(),
+				);
+			}
+			assert_eq!(crate::AmountVoters::<Test>::get(), Some(3));
+		});
+	}
+
+	#[test]
+	fn skips_registered_voters() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+
+			assert_ok!(Voting::batch_register_voters(
+				RuntimeOrigin::root(),
+				batch(vec![1, 2, 3, 3])
+			));
+			assert_eq!(crate::AmountVoters::<Test>::get(), Some(3));
+			assert_eq!(System::events().len(), 3);
+		});
+	}
+
+	#[test]
+	fn batch_over_max_voters() {
+		new_test_ext().execute_with(|| {
+			MaxVoters::set(2);
+			assert_noop!(
+				Voting::batch_register_voters(RuntimeOrigin::root(), batch(vec![1, 2, 3])),
+				Error::<Test>::MaxVotersLimitReached
+			);
+		});
+	}
+
+	#[test]
+	fn batch_invalid_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Voting::batch_register_voters(RuntimeOrigin::signed(1), batch(vec![2])),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+	}
+}
+
 mod create_proposal {
 	use super::*;
 
@@ -57,14 +141,248 @@ mod create_proposal {
 			let new_proposal_id = initial_proposal_id + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
 
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 			assert!(Voting::proposal_exists(new_proposal_id));
 
 			System::assert_has_event(
-				Event::ProposalSubmitted { proposal_id: new_proposal_id, who: 1 }.into(),
+				Event::ProposalSubmitted { proposal_id: new_proposal_id, who: 1, end_block: 90 }
+					.into(),
 			);
 
 			assert_eq!(initial_proposal_id + 1, Voting::get_proposal_counter());
+			assert_eq!(Voting::get_proposal(&new_proposal_id).unwrap().created_at, 82);
+		});
+	}
+
+	#[test]
+	fn proposal_over_max_duration() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(10);
+			MaxProposalDuration::set(100);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					111,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::ProposalDurationTooLong
+			);
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				110,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+		});
+	}
+
+	#[test]
+	fn proposal_under_min_duration() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(10);
+			MinProposalDuration::set(5);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					14,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::ProposalDurationTooShort
+			);
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				15,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+		});
+	}
+
+	#[test]
+	fn proposal_cooldown() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(10);
+			ProposalCooldown::set(5);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			System::set_block_number(14);
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					90,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::ProposalCooldownActive
+			);
+			//The cooldown is tracked per proposer
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(2),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			System::set_block_number(15);
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+		});
+	}
+
+	#[test]
+	fn proposal_empty_description() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::zero(),
+					90,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::EmptyDescription
+			);
+		});
+	}
+
+	#[test]
+	fn proposal_min_turnout_too_high() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					90,
+					Some(MaxProposalVotes::get() + 1),
+					None,
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::MinTurnoutTooHigh
+			);
+		});
+	}
+
+	#[test]
+	fn proposal_counter_and_active_count() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for _ in 0..2 {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					90,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 1));
+
+			assert_eq!(Voting::get_proposal_counter(), 2);
+			assert_eq!(Voting::active_proposal_count(), 1);
+		});
+	}
+
+	#[test]
+	fn proposal_id_overflow() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			crate::ProposalCounter::<Test>::put(u32::MAX - 1);
+
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_eq!(Voting::get_proposal_counter(), u32::MAX);
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					90,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::ProposalIdToHigh
+			);
 		});
 	}
 
@@ -75,7 +393,16 @@ mod create_proposal {
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
 
 			assert_noop!(
-				Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 80),
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					80,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
 				Error::<Test>::TimePeriodToLow
 			);
 		});
@@ -87,7 +414,16 @@ mod create_proposal {
 			System::set_block_number(82);
 
 			assert_noop!(
-				Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90),
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					90,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
 				Error::<Test>::VoterIsNotRegistered
 			);
 		});
@@ -104,7 +440,16 @@ mod increase_proposal_time {
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
 
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 			assert_ok!(Voting::increase_proposal_time(RuntimeOrigin::signed(1), proposal_id, 95));
 
 			System::assert_has_event(Event::ProposalUpdated { proposal_id, end_block: 95 }.into());
@@ -114,13 +459,84 @@ mod increase_proposal_time {
 		});
 	}
 
+	#[test]
+	fn admin_extends_proposal() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(30);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			for voter in 1..=2 {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			assert_ok!(Voting::increase_proposal_time(RuntimeOrigin::root(), proposal_id, 95));
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().time_period, 95);
+
+			assert_noop!(
+				Voting::increase_proposal_time(RuntimeOrigin::signed(2), proposal_id, 99),
+				Error::<Test>::Unauthorized
+			);
+			assert_noop!(
+				Voting::increase_proposal_time(RuntimeOrigin::root(), proposal_id, 95),
+				Error::<Test>::TimePeriodToLow
+			);
+		});
+	}
+
+	#[test]
+	fn extension_over_max_duration() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(30);
+			MaxProposalDuration::set(100);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().created_at, 30);
+
+			//The duration is counted from the creation block, not the current one
+			System::set_block_number(80);
+			assert_noop!(
+				Voting::increase_proposal_time(RuntimeOrigin::signed(1), proposal_id, 131),
+				Error::<Test>::ProposalDurationTooLong
+			);
+			assert_ok!(Voting::increase_proposal_time(RuntimeOrigin::signed(1), proposal_id, 130));
+		});
+	}
+
 	#[test]
 	fn proposer_not_registered() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(30);
 
 			assert_noop!(
-				Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90),
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					90,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
 				Error::<Test>::VoterIsNotRegistered
 			);
 		});
@@ -133,7 +549,16 @@ mod increase_proposal_time {
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
 
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 			assert_noop!(
 				Voting::increase_proposal_time(RuntimeOrigin::signed(1), proposal_id, 75),
 				Error::<Test>::TimePeriodToLow
@@ -149,13 +574,54 @@ mod increase_proposal_time {
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
 
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 			assert_noop!(
 				Voting::increase_proposal_time(RuntimeOrigin::signed(2), proposal_id, 95),
 				Error::<Test>::Unauthorized
 			);
 		});
 	}
+
+	#[test]
+	fn ended_proposal_cant_be_extended() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(30);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+			//Extending it would push the conviction locks of its voters further out
+			assert_noop!(
+				Voting::increase_proposal_time(RuntimeOrigin::signed(1), proposal_id, 95),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+			assert_noop!(
+				Voting::increase_proposal_time(RuntimeOrigin::root(), proposal_id, 95),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().time_period, 90);
+		});
+	}
 }
 
 mod cancel_proposal {
@@ -168,7 +634,16 @@ mod cancel_proposal {
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
 
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
 			System::assert_has_event(Event::ProposalCanceled { proposal_id }.into());
 
@@ -177,6 +652,55 @@ mod cancel_proposal {
 		});
 	}
 
+	#[test]
+	fn voters_unlock_after_cancel() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(30);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			for voter in 1..=2 {
+				Balances::make_free_balance_be(&voter, 25u32.into());
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(3)));
+			assert_eq!(Balances::reserved_balance(&2), 9);
+
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalSettled { proposal_id, affected_voters: 2 }.into(),
+			);
+			for voter in 1..=2 {
+				System::assert_has_event(Event::BalanceUnlocked { proposal_id, who: voter }.into());
+				assert_eq!(Balances::reserved_balance(&voter), 0);
+				assert_eq!(Balances::free_balance(&voter), 25);
+				assert_eq!(Voting::get_vote(&voter, &proposal_id), None);
+			}
+			assert_eq!(Voting::voter_turnout(&proposal_id), 0);
+
+			//Nothing is left to unlock
+			for voter in 1..=2 {
+				assert_noop!(
+					Voting::unlock_balance(RuntimeOrigin::signed(voter), proposal_id),
+					Error::<Test>::AlreadyRefunded
+				);
+			}
+			assert_noop!(
+				Voting::unlock_balance(RuntimeOrigin::signed(3), proposal_id),
+				Error::<Test>::VoteNotFound
+			);
+		});
+	}
+
 	#[test]
 	fn proposal_cant_be_canceled() {
 		new_test_ext().execute_with(|| {
@@ -184,7 +708,16 @@ mod cancel_proposal {
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
 
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 
 			System::set_block_number(100);
 
@@ -198,61 +731,312 @@ mod cancel_proposal {
 
 mod vote {
 	use super::*;
+	use frame_support::traits::NamedReservableCurrency;
 
 	#[test]
-	fn cast_valid_votes() {
+	fn get_vote_returns_stored_vote() {
 		new_test_ext().execute_with(|| {
-			//Initial setup
-			System::set_block_number(1);
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
-			let initial_balance: u32 = 25;
-			Balances::make_free_balance_be(&1, initial_balance.into());
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
-
-			//Vote in favor and verify that the functions excecutes properly and the event is
-			// created
-			let vote_amount: u32 = 2;
-			assert_ok!(Voting::vote(
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::make_proposal(
 				RuntimeOrigin::signed(1),
-				proposal_id,
-				VoteDecision::Aye(vote_amount)
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
 			));
-			System::assert_has_event(Event::VoteCasted { proposal_id, who: 1 }.into());
-
-			//Check that the reserved amount from the user is (amount of votes^2)
-			let user_balance = Balances::free_balance(&1);
-			assert_eq!(initial_balance, user_balance as u32 + vote_amount.pow(2));
+			assert_eq!(Voting::get_vote(&1, &proposal_id), None);
 
-			//Check that the vote is in storage and the proposal updated properly
-			assert!(Voting::vote_casted(&1, &proposal_id));
-			let updated_proposal: Proposal<Test> = Voting::get_proposal(&proposal_id).unwrap();
-			assert_eq!(updated_proposal.ayes, vote_amount);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Nay(3)));
 
-			//Vote nay and verify that the changes are correct in storage
-			Balances::make_free_balance_be(&2, 25u32.into());
-			assert_ok!(Voting::vote(
-				RuntimeOrigin::signed(2),
-				proposal_id,
-				VoteDecision::Nay(vote_amount)
-			));
-			System::assert_has_event(Event::VoteCasted { proposal_id, who: 2 }.into());
-			assert!(Voting::vote_casted(&2, &proposal_id));
-			let updated_proposal: Proposal<Test> = Voting::get_proposal(&proposal_id).unwrap();
-			assert_eq!(updated_proposal.nays, vote_amount);
+			let vote = Voting::get_vote(&1, &proposal_id).unwrap();
+			assert_eq!(vote.vote_decision, VoteDecision::Nay(3));
+			assert!(vote.locked);
 		});
 	}
 
 	#[test]
-	fn voter_not_registered() {
+	fn not_enough_balance() {
 		new_test_ext().execute_with(|| {
-			//Initial setup
-			System::set_block_number(1);
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
-
+			Balances::make_free_balance_be(&1, 5u32.into());
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)),
+				Error::<Test>::InsufficientBalanceForVote
+			);
+		});
+	}
+
+	#[test]
+	fn reserves_under_pallet_name() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_eq!(Voting::reserve_id(), *b"py/votin");
+			assert_eq!(Balances::reserved_balance_named(&Voting::reserve_id(), &1), 9);
+
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Balances::reserved_balance_named(&Voting::reserve_id(), &1), 0);
+		});
+	}
+
+	#[test]
+	fn total_reserved() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			Balances::make_free_balance_be(&1, 100u32.into());
+			for _ in 0..3 {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					90,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			assert_eq!(Voting::total_reserved(&1), 0);
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(2)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 2, VoteDecision::Nay(3)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, VoteDecision::Abstain(4)));
+
+			assert_eq!(Voting::total_reserved(&1), 4 + 9 + 16);
+			assert_eq!(Voting::total_reserved(&2), 0);
+		});
+	}
+
+	#[test]
+	fn per_proposal_vote_limit() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				Some(2),
+				None,
+				vec![],
+				None
+			));
+			let proposal_id = Voting::get_proposal_counter();
+			assert!(VoteLimit::get() > 3);
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)),
+				Error::<Test>::VoteAmountLimit
+			);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_noop!(
+				Voting::update_vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)),
+				Error::<Test>::VoteAmountLimit
+			);
+		});
+	}
+
+	#[test]
+	fn min_vote_amount() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			MinVoteAmount::set(2);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			let proposal_id = Voting::get_proposal_counter();
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)),
+				Error::<Test>::VoteAmountTooLow
+			);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_noop!(
+				Voting::update_vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)),
+				Error::<Test>::VoteAmountTooLow
+			);
+		});
+	}
+
+	#[test]
+	fn zero_per_proposal_vote_limit() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					5,
+					None,
+					Some(0),
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::InvalidVoteAmount
+			);
+		});
+	}
+
+	#[test]
+	fn reserved_for_proposal() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_eq!(Voting::reserved_for_proposal(&1, &proposal_id), 0);
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(4)));
+			assert_eq!(Voting::reserved_for_proposal(&1, &proposal_id), 16);
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Voting::reserved_for_proposal(&1, &proposal_id), 0);
+		});
+	}
+
+	#[test]
+	fn cast_valid_votes() {
+		new_test_ext().execute_with(|| {
+			//Initial setup
+			System::set_block_number(1);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+			let initial_balance: u32 = 25;
+			Balances::make_free_balance_be(&1, initial_balance.into());
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			//Vote in favor and verify that the functions excecutes properly and the event is
+			// created
+			let vote_amount: u32 = 2;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(vote_amount)
+			));
+			System::assert_has_event(
+				Event::VoteCasted {
+					proposal_id,
+					who: 1,
+					vote_decision: VoteDecision::Aye(vote_amount),
+				}
+				.into(),
+			);
+
+			//Check that the reserved amount from the user is (amount of votes^2)
+			let user_balance = Balances::free_balance(&1);
+			assert_eq!(initial_balance, user_balance as u32 + vote_amount.pow(2));
+
+			//Check that the vote is in storage and the proposal updated properly
+			assert!(Voting::vote_casted(&1, &proposal_id));
+			let updated_proposal: Proposal<Test> = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(updated_proposal.tally.ayes, vote_amount);
+
+			//Vote nay and verify that the changes are correct in storage
+			Balances::make_free_balance_be(&2, 25u32.into());
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDecision::Nay(vote_amount)
+			));
+			System::assert_has_event(
+				Event::VoteCasted {
+					proposal_id,
+					who: 2,
+					vote_decision: VoteDecision::Nay(vote_amount),
+				}
+				.into(),
+			);
+			assert!(Voting::vote_casted(&2, &proposal_id));
+			let updated_proposal: Proposal<Test> = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(updated_proposal.tally.nays, vote_amount);
+		});
+	}
+
+	#[test]
+	fn voter_not_registered() {
+		new_test_ext().execute_with(|| {
+			//Initial setup
+			System::set_block_number(1);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
 			assert_noop!(
 				Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Aye(1)),
 				Error::<Test>::VoterIsNotRegistered
@@ -268,7 +1052,16 @@ mod vote {
 			Balances::make_free_balance_be(&1, 25u32.into());
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 
 			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
 			assert_noop!(
@@ -286,7 +1079,16 @@ mod vote {
 			Balances::make_free_balance_be(&1, 25u32.into());
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 
 			let vote_limit: u32 = VoteLimit::get();
 			assert_noop!(
@@ -324,7 +1126,16 @@ mod vote {
 			Balances::make_free_balance_be(&1, 25u32.into());
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 10));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				10,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 
 			System::set_block_number(20);
 
@@ -342,7 +1153,16 @@ mod vote {
 			System::set_block_number(1);
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 90));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				90,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 
 			assert_noop!(
 				Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(0)),
@@ -362,7 +1182,16 @@ mod finish_proposal {
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			Balances::make_free_balance_be(&1, 25u32.into());
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 5));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 
 			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
 
@@ -370,7 +1199,88 @@ mod finish_proposal {
 
 			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
 			System::assert_has_event(
-				Event::ProposalEnded { proposal_id, status: ProposalStatus::Passed }.into(),
+				Event::VotesTallied { proposal_id, ayes: 1, nays: 0, abstains: 0 }.into(),
+			);
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Passed,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn settled_event_counts_locked_votes() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			for voter in 1..=3 {
+				Balances::make_free_balance_be(&voter, 25u32.into());
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			for _ in 0..2 {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					50,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(1)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 1, VoteDecision::Nay(2)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(3), 1, VoteDecision::Aye(1)));
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(3), 1));
+
+			System::set_block_number(51);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 1));
+			System::assert_last_event(
+				Event::ProposalSettled { proposal_id: 1, affected_voters: 2 }.into(),
+			);
+
+			//Proposals without votes leave nothing to reclaim
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 2));
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::Voting(Event::ProposalSettled { proposal_id: 2, .. })
+			)));
+		});
+	}
+
+	#[test]
+	fn flags_unregistered_proposer() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			//The proposer stops being a registered voter after making the proposal
+			crate::RegisteredVoters::<Test>::remove(1);
+			System::set_block_number(6);
+
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(2), proposal_id));
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Expired,
+					proposer_still_registered: false,
+				}
+				.into(),
 			);
 		});
 	}
@@ -382,7 +1292,16 @@ mod finish_proposal {
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			Balances::make_free_balance_be(&1, 25u32.into());
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 5));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 
 			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Nay(1)));
 
@@ -390,62 +1309,304 @@ mod finish_proposal {
 
 			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
 			System::assert_has_event(
-				Event::ProposalEnded { proposal_id, status: ProposalStatus::Rejected }.into(),
+				Event::VotesTallied { proposal_id, ayes: 0, nays: 1, abstains: 0 }.into(),
+			);
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Rejected,
+					proposer_still_registered: true,
+				}
+				.into(),
 			);
 		});
 	}
 
 	#[test]
-	fn proposal_tied() {
+	fn proposal_without_quorum() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
-
+			Quorum::set(2);
 			let proposal_id = Voting::get_proposal_counter() + 1;
+			Balances::make_free_balance_be(&1, 25u32.into());
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 5));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
 
 			System::set_block_number(6);
 
 			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
 			System::assert_has_event(
-				Event::ProposalEnded { proposal_id, status: ProposalStatus::Tied }.into(),
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::NoQuorum,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+			assert_eq!(
+				Voting::get_proposal(&proposal_id).unwrap().status,
+				ProposalStatus::NoQuorum
 			);
 		});
 	}
 
 	#[test]
-	fn finish_proposal_fails_if_canceled() {
+	fn proposal_below_own_min_turnout() {
 		new_test_ext().execute_with(|| {
 			System::set_block_number(1);
-			let proposal_id = Voting::get_proposal_counter() + 1;
-			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 5));
-			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+			Quorum::set(1);
+			for voter in 1..=3 {
+				Balances::make_free_balance_be(&voter, 25u32.into());
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			let strict_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				Some(3),
+				None,
+				None,
+				vec![],
+				None
+			));
+			let lenient_id = strict_id + 1;
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(2),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			for proposal_id in [strict_id, lenient_id] {
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(1),
+					proposal_id,
+					VoteDecision::Aye(2)
+				));
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(2),
+					proposal_id,
+					VoteDecision::Aye(1)
+				));
+			}
 
-			assert_noop!(
-				Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id),
-				Error::<Test>::ProposalAlreadyEnded
-			);
+			System::set_block_number(6);
+
+			//Two voters meet the global quorum but not the proposal's own threshold
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), strict_id));
+			assert_eq!(Voting::get_proposal(&strict_id).unwrap().status, ProposalStatus::NoQuorum);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), lenient_id));
+			assert_eq!(Voting::get_proposal(&lenient_id).unwrap().status, ProposalStatus::Passed);
 		});
 	}
 
 	#[test]
-	fn finish_proposal_early_rejects() {
+	fn proposal_tied() {
 		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
 			let proposal_id = Voting::get_proposal_counter() + 1;
-			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 5));
+			for voter in 1..=2 {
+				Balances::make_free_balance_be(&voter, 25u32.into());
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(3)));
 
-			assert_noop!(
-				Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id),
-				Error::<Test>::ProposalAlreadyEnded
+			System::set_block_number(6);
+
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Tied,
+					proposer_still_registered: true,
+				}
+				.into(),
 			);
 		});
 	}
-}
 
-mod unlock_balance {
-	use super::*;
+	#[test]
+	fn proposal_tie_rejected() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			RejectTies::set(true);
+
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			for voter in 1..=2 {
+				Balances::make_free_balance_be(&voter, 25u32.into());
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(3)));
+
+			System::set_block_number(6);
+
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Rejected,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn proposal_expired() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			System::set_block_number(6);
+
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Expired,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn finish_proposal_fails_if_canceled() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+			assert_noop!(
+				Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+		});
+	}
+
+	#[test]
+	fn finish_proposal_early_rejects() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			assert_noop!(
+				Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+		});
+	}
+
+	#[test]
+	fn non_registered_account_finishes() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
+
+			System::set_block_number(6);
+
+			assert!(!Voting::is_registered(&2));
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(2), proposal_id));
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Passed,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+		});
+	}
+}
+
+mod unlock_balance {
+	use super::*;
 
 	fn before_each() -> (u32, u32) {
 		// initial setup
@@ -454,7 +1615,16 @@ mod unlock_balance {
 		let initial_balance: u32 = 25;
 		Balances::make_free_balance_be(&1, initial_balance.into());
 		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-		assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 5));
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
 
 		(initial_balance, proposal_id)
 	}
@@ -477,6 +1647,51 @@ mod unlock_balance {
 		});
 	}
 
+	#[test]
+	fn post_result_lock() {
+		new_test_ext().execute_with(|| {
+			PostResultLock::set(10);
+			let (_, proposal_id) = before_each();
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+			//The proposal ended at block 5
+			System::set_block_number(14);
+			assert_noop!(
+				Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::StillLocked
+			);
+
+			System::set_block_number(15);
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Balances::reserved_balance(&1), 0);
+		});
+	}
+
+	#[test]
+	fn post_result_lock_starts_when_finished_early() {
+		new_test_ext().execute_with(|| {
+			PostResultLock::set(10);
+			let (_, proposal_id) = before_each();
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			System::set_block_number(2);
+			assert_ok!(Voting::force_finish_proposal(RuntimeOrigin::root(), proposal_id));
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().ended_at, Some(2));
+
+			System::set_block_number(11);
+			assert_noop!(
+				Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::StillLocked
+			);
+
+			System::set_block_number(12);
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id));
+		});
+	}
+
 	#[test]
 	fn cant_unlock_before_proposal_end() {
 		new_test_ext().execute_with(|| {
@@ -528,6 +1743,7 @@ mod unlock_balance {
 
 mod cancel_vote {
 	use super::*;
+	use frame_support::traits::NamedReservableCurrency;
 
 	//Returns (initial_balance, proposal_id)
 	fn before_each(time_limit: u64) -> (u32, u32) {
@@ -539,8 +1755,13 @@ mod cancel_vote {
 		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
 		assert_ok!(Voting::make_proposal(
 			RuntimeOrigin::signed(1),
-			sp_core::H256::zero(),
-			time_limit
+			sp_core::H256::repeat_byte(1),
+			time_limit,
+			None,
+			None,
+			None,
+			vec![],
+			None
 		));
 
 		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
@@ -554,7 +1775,10 @@ mod cancel_vote {
 			let (initial_balance, proposal_id) = before_each(40);
 
 			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
-			System::assert_has_event(Event::VoteCanceled { proposal_id, who: 1 }.into());
+			System::assert_has_event(
+				Event::VoteCanceled { proposal_id, who: 1, vote_decision: VoteDecision::Aye(3) }
+					.into(),
+			);
 
 			//Check that the reserved amount from the user is (amount of votes^2)
 			let current_balance = Balances::free_balance(&1);
@@ -562,6 +1786,44 @@ mod cancel_vote {
 		});
 	}
 
+	#[test]
+	fn refunds_stored_reserve() {
+		new_test_ext().execute_with(|| {
+			let (_, proposal_id) = before_each(40);
+			assert_eq!(Voting::get_vote(&1, &proposal_id).unwrap().reserved, 9);
+
+			//The refund follows the stored reserve rather than the vote amount
+			assert_ok!(Balances::reserve_named(&Voting::reserve_id(), &1, 5));
+			crate::Votes::<Test>::mutate(1, proposal_id, |vote| {
+				if let Some(v) = vote.as_mut() {
+					v.reserved = 14
+				}
+			});
+
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Balances::reserved_balance(&1), 0);
+		});
+	}
+
+	#[test]
+	fn returns_reserve_of_updated_vote() {
+		new_test_ext().execute_with(|| {
+			let (initial_balance, proposal_id) = before_each(40);
+
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(4)
+			));
+			assert_eq!(Balances::reserved_balance(&1), 16);
+
+			//The whole reserve of the updated vote comes back, not the one of the original vote
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Balances::free_balance(&1), initial_balance as u128);
+		});
+	}
+
 	#[test]
 	fn cant_cancel_after_thresshold() {
 		new_test_ext().execute_with(|| {
@@ -600,7 +1862,16 @@ mod cancel_vote {
 			Balances::make_free_balance_be(&1, 25u32.into());
 			let proposal_id = Voting::get_proposal_counter() + 1;
 			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
-			assert_ok!(Voting::make_proposal(RuntimeOrigin::signed(1), sp_core::H256::zero(), 5));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
 
 			assert_noop!(
 				Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id),
@@ -622,6 +1893,50 @@ mod cancel_vote {
 			);
 		});
 	}
+
+	#[test]
+	fn unlock_after_cancel() {
+		new_test_ext().execute_with(|| {
+			let (_, proposal_id) = before_each(40);
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+
+			assert_noop!(
+				Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::AlreadyRefunded
+			);
+
+			//Voting again clears the refund mark
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
+			assert_noop!(
+				Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ProposalInProgress
+			);
+		});
+	}
+
+	#[test]
+	fn end_block_is_closed() {
+		new_test_ext().execute_with(|| {
+			let (_, proposal_id) = before_each(40);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+			Balances::make_free_balance_be(&2, 25u32.into());
+
+			System::set_block_number(39);
+			assert!(Voting::is_proposal_active(&Voting::get_proposal(&proposal_id).unwrap()));
+
+			//Voting closes at the end block itself
+			System::set_block_number(40);
+			assert!(!Voting::is_proposal_active(&Voting::get_proposal(&proposal_id).unwrap()));
+			assert_noop!(
+				Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Aye(1)),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+		});
+	}
 }
 
 mod update_vote {
@@ -635,13 +1950,52 @@ mod update_vote {
 		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
 		assert_ok!(Voting::make_proposal(
 			RuntimeOrigin::signed(1),
-			sp_core::H256::zero(),
-			proposal_end.into()
+			sp_core::H256::repeat_byte(1),
+			proposal_end.into(),
+			None,
+			None,
+			None,
+			vec![],
+			None
 		));
 
 		(initial_balance, proposal_id)
 	}
 
+	#[test]
+	fn end_block_is_closed() {
+		new_test_ext().execute_with(|| {
+			let (_, proposal_id) = before_each(50);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+			Balances::make_free_balance_be(&2, 25u32.into());
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+
+			//Vote, update and cancel are all rejected at the end block
+			System::set_block_number(50);
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Aye(1)),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+			assert_noop!(
+				Voting::update_vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(4)),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+			assert_noop!(
+				Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+
+			//And all accepted the block before it
+			System::set_block_number(49);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Aye(1)));
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(4)
+			));
+		});
+	}
+
 	#[test]
 	fn increase_yes_vote() {
 		new_test_ext().execute_with(|| {
@@ -667,6 +2021,8 @@ mod update_vote {
 					who: 1,
 					previous: VoteDecision::Aye(vote_amount),
 					new: VoteDecision::Aye(vote_amount + 1),
+					amount_delta: 1,
+					direction_changed: false,
 				}
 				.into(),
 			);
@@ -679,7 +2035,32 @@ mod update_vote {
 				initial_balance as u128,
 				current_balance + ((vote_amount + 1) as u128).pow(2)
 			);
-			assert_eq!(proposal_before_update.ayes + 1, proposal_after_update.ayes);
+			assert_eq!(proposal_before_update.tally.ayes + 1, proposal_after_update.tally.ayes);
+		});
+	}
+
+	#[test]
+	fn stores_updated_reserve() {
+		new_test_ext().execute_with(|| {
+			let (_, proposal_id) = before_each(50);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_eq!(Voting::get_vote(&1, &proposal_id).unwrap().reserved, 9);
+
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(4)
+			));
+			assert_eq!(Voting::get_vote(&1, &proposal_id).unwrap().reserved, 16);
+			assert_eq!(Balances::reserved_balance(&1), 16);
+
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Nay(2)
+			));
+			assert_eq!(Voting::get_vote(&1, &proposal_id).unwrap().reserved, 4);
+			assert_eq!(Balances::reserved_balance(&1), 4);
 		});
 	}
 
@@ -708,6 +2089,8 @@ mod update_vote {
 					who: 1,
 					previous: VoteDecision::Aye(vote_amount),
 					new: VoteDecision::Aye(vote_amount - 1),
+					amount_delta: -1,
+					direction_changed: false,
 				}
 				.into(),
 			);
@@ -720,7 +2103,7 @@ mod update_vote {
 				initial_balance as u128,
 				current_balance + ((vote_amount - 1) as u128).pow(2)
 			);
-			assert_eq!(proposal_before_update.ayes - 1, proposal_after_update.ayes);
+			assert_eq!(proposal_before_update.tally.ayes - 1, proposal_after_update.tally.ayes);
 		});
 	}
 
@@ -749,6 +2132,8 @@ mod update_vote {
 					who: 1,
 					previous: VoteDecision::Nay(vote_amount),
 					new: VoteDecision::Nay(vote_amount + 1),
+					amount_delta: 1,
+					direction_changed: false,
 				}
 				.into(),
 			);
@@ -761,7 +2146,7 @@ mod update_vote {
 				initial_balance as u128,
 				current_balance + ((vote_amount + 1) as u128).pow(2)
 			);
-			assert_eq!(proposal_before_update.nays + 1, proposal_after_update.nays);
+			assert_eq!(proposal_before_update.tally.nays + 1, proposal_after_update.tally.nays);
 		});
 	}
 
@@ -790,6 +2175,8 @@ mod update_vote {
 					who: 1,
 					previous: VoteDecision::Nay(vote_amount),
 					new: VoteDecision::Nay(vote_amount - 1),
+					amount_delta: -1,
+					direction_changed: false,
 				}
 				.into(),
 			);
@@ -802,7 +2189,7 @@ mod update_vote {
 				initial_balance as u128,
 				current_balance + ((vote_amount - 1) as u128).pow(2)
 			);
-			assert_eq!(proposal_before_update.nays - 1, proposal_after_update.nays);
+			assert_eq!(proposal_before_update.tally.nays - 1, proposal_after_update.tally.nays);
 		});
 	}
 
@@ -831,6 +2218,8 @@ mod update_vote {
 					who: 1,
 					previous: VoteDecision::Aye(vote_amount),
 					new: VoteDecision::Nay(vote_amount),
+					amount_delta: 0,
+					direction_changed: true,
 				}
 				.into(),
 			);
@@ -840,36 +2229,94 @@ mod update_vote {
 			//Check that the reserved amount from the user is (amount of votes^2)
 			let current_balance = Balances::free_balance(&1);
 			assert_eq!(initial_balance as u128, current_balance + ((vote_amount) as u128).pow(2));
-			assert_eq!(proposal_before_update.ayes, proposal_after_update.ayes + vote_amount);
-			assert_eq!(proposal_before_update.nays, proposal_after_update.nays - vote_amount);
+			assert_eq!(
+				proposal_before_update.tally.ayes,
+				proposal_after_update.tally.ayes + vote_amount
+			);
+			assert_eq!(
+				proposal_before_update.tally.nays,
+				proposal_after_update.tally.nays - vote_amount
+			);
 		});
 	}
 
 	#[test]
-	fn vote_over_limit() {
+	fn switch_side_and_increase() {
 		new_test_ext().execute_with(|| {
-			let (_, proposal_id) = before_each(50);
+			let (initial_balance, proposal_id) = before_each(50);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_eq!(Balances::reserved_balance(&1), 4);
 
-			let vote_amount: u32 = 3;
-			assert_ok!(Voting::vote(
+			assert_ok!(Voting::update_vote(
 				RuntimeOrigin::signed(1),
 				proposal_id,
-				VoteDecision::Aye(vote_amount)
+				VoteDecision::Nay(4)
 			));
 
-			let vote_limit: u32 = VoteLimit::get();
-			assert_noop!(
-				Voting::update_vote(
-					RuntimeOrigin::signed(1),
-					proposal_id,
-					VoteDecision::Aye(vote_limit + 1)
-				),
-				Error::<Test>::VoteAmountLimit
-			);
+			//The aye points leave the tallies and the nay points are added in full
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.tally.ayes, proposal.tally.nays), (0, 4));
+			assert_eq!(Balances::reserved_balance(&1), 16);
+			assert_eq!(Balances::free_balance(&1), initial_balance as u128 - 16);
 		});
 	}
+
 	#[test]
-	fn vote_not_found() {
+	fn switch_side_and_decrease() {
+		new_test_ext().execute_with(|| {
+			let (initial_balance, proposal_id) = before_each(50);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(4)));
+			assert_eq!(Balances::reserved_balance(&1), 16);
+
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Nay(2)
+			));
+			System::assert_last_event(
+				Event::<Test>::VoteUpdated {
+					proposal_id,
+					who: 1,
+					previous: VoteDecision::Aye(4),
+					new: VoteDecision::Nay(2),
+					amount_delta: -2,
+					direction_changed: true,
+				}
+				.into(),
+			);
+
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.tally.ayes, proposal.tally.nays), (0, 2));
+			assert_eq!(Balances::reserved_balance(&1), 4);
+			assert_eq!(Balances::free_balance(&1), initial_balance as u128 - 4);
+		});
+	}
+
+	#[test]
+	fn vote_over_limit() {
+		new_test_ext().execute_with(|| {
+			let (_, proposal_id) = before_each(50);
+
+			let vote_amount: u32 = 3;
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(vote_amount)
+			));
+
+			let vote_limit: u32 = VoteLimit::get();
+			assert_noop!(
+				Voting::update_vote(
+					RuntimeOrigin::signed(1),
+					proposal_id,
+					VoteDecision::Aye(vote_limit + 1)
+				),
+				Error::<Test>::VoteAmountLimit
+			);
+		});
+	}
+	#[test]
+	fn vote_not_found() {
 		new_test_ext().execute_with(|| {
 			let (_, proposal_id) = before_each(50);
 
@@ -992,3 +2439,4382 @@ mod update_vote {
 		});
 	}
 }
+
+mod vote_count {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		for voter in 1..=3 {
+			Balances::make_free_balance_be(&voter, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			50,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+
+		proposal_id
+	}
+
+	#[test]
+	fn vote_increases_turnout() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_eq!(Voting::voter_turnout(&proposal_id), 0);
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(3)));
+			assert_eq!(Voting::voter_turnout(&proposal_id), 2);
+		});
+	}
+
+	#[test]
+	fn update_keeps_turnout() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Nay(3)
+			));
+			assert_eq!(Voting::voter_turnout(&proposal_id), 1);
+		});
+	}
+
+	#[test]
+	fn cancel_decreases_turnout() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Aye(1)));
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Voting::voter_turnout(&proposal_id), 1);
+		});
+	}
+}
+
+mod abstain {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		for voter in 1..=2 {
+			Balances::make_free_balance_be(&voter, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+
+		proposal_id
+	}
+
+	#[test]
+	fn abstain_reserves_and_counts_turnout() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDecision::Abstain(3)
+			));
+
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(
+				(proposal.tally.ayes, proposal.tally.nays, proposal.tally.abstains),
+				(0, 0, 3)
+			);
+			assert_eq!(Voting::voter_turnout(&proposal_id), 1);
+			assert_eq!(Balances::reserved_balance(&2), 9);
+		});
+	}
+
+	#[test]
+	fn abstain_does_not_change_outcome() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Nay(1)));
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDecision::Abstain(4)
+			));
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Rejected,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn cancel_abstain() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::increase_proposal_time(RuntimeOrigin::signed(1), proposal_id, 50));
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDecision::Abstain(3)
+			));
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(2), proposal_id));
+
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(proposal.tally.abstains, 0);
+			assert_eq!(Balances::free_balance(&2), 25);
+		});
+	}
+}
+
+mod proposal_state {
+	use super::*;
+
+	#[test]
+	fn projects_current_result() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			let state = Voting::proposal_state(proposal_id).unwrap();
+			assert_eq!(state.projected_status, ProposalStatus::Expired);
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			let state = Voting::proposal_state(proposal_id).unwrap();
+			assert_eq!((state.ayes, state.nays, state.turnout), (2, 0, 1));
+			assert_eq!(state.time_period, 5);
+			assert_eq!(state.projected_status, ProposalStatus::Passed);
+
+			//Projection does not modify the stored proposal
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(proposal.status, ProposalStatus::InProgress);
+		});
+	}
+
+	#[test]
+	fn ended_proposal_reports_final_status() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+			let state = Voting::proposal_state(proposal_id).unwrap();
+			assert_eq!(state.projected_status, ProposalStatus::Canceled);
+			assert!(Voting::proposal_state(proposal_id + 1).is_none());
+		});
+	}
+}
+
+mod removal_threshold {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		Balances::make_free_balance_be(&1, 25u32.into());
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			40,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+
+		proposal_id
+	}
+
+	#[test]
+	fn expired_proposal_does_not_underflow() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(50);
+			let proposal =
+				Proposal::<Test>::new(1, 1, sp_core::H256::repeat_byte(1), 40, 0, None, None);
+			assert!(Voting::passed_removal_threshold(&proposal));
+		});
+	}
+
+	#[test]
+	fn cancel_vote_after_end() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::set_block_number(41);
+
+			assert_noop!(
+				Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+		});
+	}
+
+	#[test]
+	fn update_vote_after_end() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::set_block_number(41);
+
+			assert_noop!(
+				Voting::update_vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+		});
+	}
+
+	#[test]
+	fn per_proposal_threshold_blocks_reduction() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				100,
+				None,
+				None,
+				None,
+				vec![],
+				Some(60)
+			));
+			assert_eq!(Voting::get_proposal(&1).unwrap().removal_threshold, Some(60));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(3)));
+
+			// 50 blocks left: outside the global threshold of 20, inside the one of the proposal.
+			System::set_block_number(50);
+			assert!(!Voting::passed_removal_threshold(&Proposal::<Test>::new(
+				2,
+				1,
+				sp_core::H256::repeat_byte(1),
+				100,
+				0,
+				None,
+				None
+			)));
+			assert_noop!(
+				Voting::update_vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(1)),
+				Error::<Test>::PassedRemovalThreshold
+			);
+			assert_noop!(
+				Voting::cancel_vote(RuntimeOrigin::signed(1), 1),
+				Error::<Test>::PassedRemovalThreshold
+			);
+			assert_ok!(Voting::update_vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(4)));
+		});
+	}
+
+	#[test]
+	fn per_proposal_threshold_can_not_exceed_duration() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					40,
+					None,
+					None,
+					None,
+					vec![],
+					Some(40)
+				),
+				Error::<Test>::RemovalThresholdTooLarge
+			);
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				40,
+				None,
+				None,
+				None,
+				vec![],
+				Some(39)
+			));
+		});
+	}
+}
+
+mod delegation {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		for voter in 1..=3 {
+			Balances::make_free_balance_be(&voter, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			50,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+
+		proposal_id
+	}
+
+	#[test]
+	fn delegated_power_is_applied() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 1));
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 1));
+			System::assert_has_event(Event::Delegated { who: 2, to: 1 }.into());
+			assert_eq!(Voting::voting_multiplier(&1), 3);
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(proposal.tally.ayes, 6);
+
+			//Only the delegatee reserves balance
+			assert_eq!(Balances::reserved_balance(&1), 4);
+			assert_eq!(Balances::reserved_balance(&2), 0);
+
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(proposal.tally.ayes, 0);
+		});
+	}
+
+	#[test]
+	fn delegator_cannot_vote() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 1));
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Aye(1)),
+				Error::<Test>::VoteDelegated
+			);
+
+			assert_ok!(Voting::undelegate(RuntimeOrigin::signed(2)));
+			System::assert_has_event(Event::Undelegated { who: 2, from: 1 }.into());
+			assert_eq!(Voting::voting_multiplier(&1), 1);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Aye(1)));
+		});
+	}
+
+	#[test]
+	fn redelegation_moves_power() {
+		new_test_ext().execute_with(|| {
+			before_each();
+
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 1));
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 2));
+			assert_eq!(Voting::voting_multiplier(&1), 1);
+			assert_eq!(Voting::voting_multiplier(&2), 2);
+		});
+	}
+
+	#[test]
+	fn delegation_cycle() {
+		new_test_ext().execute_with(|| {
+			before_each();
+
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(1), 1),
+				Error::<Test>::DelegationCycle
+			);
+		});
+	}
+
+	#[test]
+	fn delegations_do_not_chain() {
+		new_test_ext().execute_with(|| {
+			before_each();
+
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 2));
+			//The power delegated to 1 would be lost, as 1 can't vote
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::DelegateeDelegates
+			);
+			//2 would pass the power of 1 on to 3 without counting it
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(2), 3),
+				Error::<Test>::HasIncomingDelegations
+			);
+			assert_eq!(Voting::voting_multiplier(&2), 2);
+		});
+	}
+
+	#[test]
+	fn delegator_with_votes_in_progress() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Aye(2)));
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::VotesInProgress
+			);
+
+			//Commitments are revealed with the power of the voter as well
+			assert_ok!(Voting::commit_vote(
+				RuntimeOrigin::signed(3),
+				proposal_id,
+				Voting::commitment_of(&proposal_id, &3, &VoteDecision::Aye(1), &[0; 32])
+			));
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::VotesInProgress
+			);
+
+			//Once the proposal ends its votes no longer count
+			System::set_block_number(61);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 1));
+		});
+	}
+
+	#[test]
+	fn delegatee_votes_keep_the_delegation() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 1));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
+			assert_noop!(
+				Voting::undelegate(RuntimeOrigin::signed(2)),
+				Error::<Test>::DelegateeVotesInProgress
+			);
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(2), 3),
+				Error::<Test>::DelegateeVotesInProgress
+			);
+
+			//Without the vote of the delegatee nothing counts the delegation anymore
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::undelegate(RuntimeOrigin::signed(2)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Aye(1)));
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().tally.ayes, 1);
+		});
+	}
+
+	#[test]
+	fn unregistering_clears_delegations() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 1));
+			assert_ok!(Voting::set_delegation_cap(RuntimeOrigin::root(), 2, Some(1)));
+
+			//The delegations of other voters would keep counting for an unregistered voter
+			assert_noop!(
+				Voting::unregister_voter(RuntimeOrigin::root(), 1),
+				Error::<Test>::HasIncomingDelegations
+			);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
+			assert_noop!(
+				Voting::unregister_voter(RuntimeOrigin::root(), 2),
+				Error::<Test>::DelegateeVotesInProgress
+			);
+
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), 2));
+			System::assert_has_event(Event::Undelegated { who: 2, from: 1 }.into());
+			assert_eq!(Voting::voting_multiplier(&1), 1);
+			assert_eq!(Voting::delegation_cap(&2), 10);
+			assert_noop!(
+				Voting::undelegate(RuntimeOrigin::signed(2)),
+				Error::<Test>::NotDelegating
+			);
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), 1));
+		});
+	}
+
+	#[test]
+	fn delegate_to_unregistered() {
+		new_test_ext().execute_with(|| {
+			before_each();
+
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(1), 4),
+				Error::<Test>::VoterIsNotRegistered
+			);
+			assert_noop!(
+				Voting::undelegate(RuntimeOrigin::signed(1)),
+				Error::<Test>::NotDelegating
+			);
+		});
+	}
+}
+
+mod status_counts {
+	use super::*;
+
+	#[test]
+	fn counts_follow_transitions() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for _ in 0..3 {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					5,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			assert_eq!(Voting::count_with_status(&ProposalStatus::InProgress), 3);
+
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 1));
+			assert_eq!(Voting::count_with_status(&ProposalStatus::InProgress), 2);
+			assert_eq!(Voting::count_with_status(&ProposalStatus::Canceled), 1);
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 2, VoteDecision::Aye(1)));
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 2));
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 3));
+
+			assert_eq!(Voting::count_with_status(&ProposalStatus::InProgress), 0);
+			assert_eq!(Voting::count_with_status(&ProposalStatus::Passed), 1);
+			assert_eq!(Voting::count_with_status(&ProposalStatus::Expired), 1);
+			assert_eq!(Voting::count_with_status(&ProposalStatus::Rejected), 0);
+		});
+	}
+}
+
+mod proposal_vote_cap {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		MaxProposalVotes::set(10);
+		for voter in 1..=3 {
+			Balances::make_free_balance_be(&voter, 100u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			50,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(7)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 1, VoteDecision::Nay(3)));
+		1
+	}
+
+	#[test]
+	fn vote_over_cap_rejected() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(3), proposal_id, VoteDecision::Aye(1)),
+				Error::<Test>::ProposalVoteCapReached
+			);
+			//Abstentions don't count towards the cap
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(3),
+				proposal_id,
+				VoteDecision::Abstain(1)
+			));
+		});
+	}
+
+	#[test]
+	fn update_accounts_for_delta() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_noop!(
+				Voting::update_vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(4)),
+				Error::<Test>::ProposalVoteCapReached
+			);
+			//Switching sides with the same points keeps the proposal at the cap
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDecision::Aye(3)
+			));
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDecision::Aye(2)
+			));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(3), proposal_id, VoteDecision::Nay(1)));
+
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(proposal.tally.ayes + proposal.tally.nays, 10);
+		});
+	}
+}
+
+mod unlock_balance_batch {
+	use super::*;
+	use frame_support::BoundedVec;
+
+	fn batch(proposal_ids: Vec<u32>) -> BoundedVec<u32, MaxBatchSize> {
+		BoundedVec::try_from(proposal_ids).unwrap()
+	}
+
+	#[test]
+	fn unlocks_finished_proposals_and_skips_the_rest() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			Balances::make_free_balance_be(&1, 100u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				50,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			for proposal_id in 1..=3 {
+				assert_ok!(Voting::vote(
+					RuntimeOrigin::signed(1),
+					proposal_id,
+					VoteDecision::Aye(2)
+				));
+			}
+			assert_eq!(Balances::reserved_balance(&1), 12);
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 1));
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 2));
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), 2));
+
+			//Proposal 2 is already unlocked, 3 is in progress and 4 doesn't exist
+			assert_ok!(Voting::unlock_balance_batch(
+				RuntimeOrigin::signed(1),
+				batch(vec![1, 2, 3, 4])
+			));
+
+			System::assert_has_event(Event::BalanceUnlocked { proposal_id: 1, who: 1 }.into());
+			System::assert_last_event(Event::BatchBalanceUnlocked { who: 1, unlocked: 1 }.into());
+			assert_eq!(Balances::reserved_balance(&1), 4);
+		});
+	}
+}
+
+mod voters_by_proposal {
+	use super::*;
+
+	#[test]
+	fn tracks_votes_and_cancellations() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			for voter in 1..=3 {
+				Balances::make_free_balance_be(&voter, 25u32.into());
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				50,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				50,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert!(Voting::voters_for(&1).is_empty());
+
+			for voter in 1..=3 {
+				assert_ok!(Voting::vote(RuntimeOrigin::signed(voter), 1, VoteDecision::Aye(1)));
+			}
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 2, VoteDecision::Nay(1)));
+			assert_eq!(Voting::voters_for(&1), vec![1, 2, 3]);
+			assert_eq!(Voting::voters_for(&2), vec![2]);
+
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(2), 1));
+			assert_eq!(Voting::voters_for(&1), vec![1, 3]);
+			assert_eq!(Voting::voters_for(&2), vec![2]);
+
+			//Updating a vote keeps the voter listed once
+			assert_ok!(Voting::update_vote(RuntimeOrigin::signed(1), 1, VoteDecision::Nay(2)));
+			assert_eq!(Voting::voters_for(&1), vec![1, 3]);
+		});
+	}
+}
+
+mod try_state {
+	use super::*;
+	use crate::{AmountVoters, Proposals, Votes};
+
+	fn before_each() {
+		System::set_block_number(1);
+		for voter in 1..=3 {
+			Balances::make_free_balance_be(&voter, 100u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			50,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(3)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 1, VoteDecision::Nay(2)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 2, VoteDecision::Aye(1)));
+	}
+
+	#[test]
+	fn holds_through_the_proposal_lifecycle() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			assert_ok!(Voting::do_try_state());
+
+			assert_ok!(Voting::update_vote(RuntimeOrigin::signed(1), 1, VoteDecision::Nay(5)));
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(2), 1));
+			assert_ok!(Voting::do_try_state());
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 2));
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), 2));
+			assert_ok!(Voting::do_try_state());
+		});
+	}
+
+	#[test]
+	fn detects_voters_count_drift() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			AmountVoters::<Test>::put(2);
+			assert!(Voting::do_try_state().is_err());
+		});
+	}
+
+	#[test]
+	fn detects_misplaced_proposal() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			let proposal = Voting::get_proposal(&1).unwrap();
+			Proposals::<Test>::insert(3, proposal);
+			assert!(Voting::do_try_state().is_err());
+		});
+	}
+
+	#[test]
+	fn detects_reserve_drift() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			Votes::<Test>::mutate(2, 1, |vote| {
+				if let Some(v) = vote.as_mut() {
+					v.vote_decision = VoteDecision::Nay(3)
+				}
+			});
+			assert!(Voting::do_try_state().is_err());
+		});
+	}
+
+	#[test]
+	fn detects_active_votes_drift() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			crate::ActiveVotes::<Test>::insert(3, 1);
+			assert!(Voting::do_try_state().is_err());
+		});
+	}
+
+	#[test]
+	fn detects_active_ids_drift() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			crate::ActiveProposalIds::<Test>::mutate(|ids| ids.retain(|(_, id)| *id != 2));
+			assert!(Voting::do_try_state().is_err());
+		});
+	}
+
+	#[cfg(feature = "try-runtime")]
+	#[test]
+	fn runs_as_hook() {
+		use frame_support::traits::Hooks;
+
+		new_test_ext().execute_with(|| {
+			before_each();
+			assert_ok!(<Voting as Hooks<u64>>::try_state(1));
+		});
+	}
+}
+
+mod force_finish_proposal {
+	use super::*;
+
+	#[test]
+	fn ends_proposal_early() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let proposal_id = Voting::get_proposal_counter() + 1;
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				50,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Nay(2)));
+
+			assert_ok!(Voting::force_finish_proposal(RuntimeOrigin::root(), proposal_id));
+			System::assert_has_event(
+				Event::VotesTallied { proposal_id, ayes: 0, nays: 2, abstains: 0 }.into(),
+			);
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Rejected,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+			assert_eq!(
+				Voting::get_proposal(&proposal_id).unwrap().status,
+				ProposalStatus::Rejected
+			);
+
+			assert_noop!(
+				Voting::force_finish_proposal(RuntimeOrigin::root(), proposal_id),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+		});
+	}
+
+	#[test]
+	fn requires_root() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				50,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			assert_noop!(
+				Voting::force_finish_proposal(RuntimeOrigin::signed(1), 1),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Voting::force_finish_proposal(RuntimeOrigin::root(), 2),
+				Error::<Test>::ProposalNotFound
+			);
+		});
+	}
+}
+
+mod set_parameters {
+	use super::*;
+
+	#[test]
+	fn overrides_configured_limits() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_eq!(Voting::vote_limit(), 7);
+			Balances::make_free_balance_be(&1, 100u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				50,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			assert_ok!(Voting::set_parameters(RuntimeOrigin::root(), Some(2), None, Some(60)));
+			System::assert_last_event(
+				Event::ParametersUpdated { vote_limit: 2, max_voters: 100, removal_threshold: 60 }
+					.into(),
+			);
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(3)),
+				Error::<Test>::VoteAmountLimit
+			);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(2)));
+			//The whole proposal is now inside the removal threshold
+			assert_noop!(
+				Voting::cancel_vote(RuntimeOrigin::signed(1), 1),
+				Error::<Test>::PassedRemovalThreshold
+			);
+		});
+	}
+
+	#[test]
+	fn max_voters_bounds() {
+		new_test_ext().execute_with(|| {
+			for voter in 1..=3 {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+
+			assert_noop!(
+				Voting::set_parameters(RuntimeOrigin::root(), None, Some(2), None),
+				Error::<Test>::MaxVotersBelowRegistered
+			);
+			assert_noop!(
+				Voting::set_parameters(RuntimeOrigin::root(), None, Some(101), None),
+				Error::<Test>::MaxVotersAboveBound
+			);
+
+			assert_ok!(Voting::set_parameters(RuntimeOrigin::root(), None, Some(3), None));
+			assert_noop!(
+				Voting::register_voter(RuntimeOrigin::root(), 4),
+				Error::<Test>::MaxVotersLimitReached
+			);
+		});
+	}
+
+	#[test]
+	fn requires_root() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Voting::set_parameters(RuntimeOrigin::signed(1), Some(2), None, None),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+	}
+}
+
+mod proposal_deposit {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		ProposalDeposit::set(10);
+		Balances::make_free_balance_be(&1, 25u32.into());
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		assert_eq!(Balances::reserved_balance(&1), 10);
+		assert_eq!(Voting::get_proposal(&proposal_id).unwrap().deposit, 10);
+
+		proposal_id
+	}
+
+	#[test]
+	fn refunded_on_finish() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalDepositRefunded { proposal_id, who: 1, amount: 10 }.into(),
+			);
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Balances::free_balance(&1), 25);
+		});
+	}
+
+	#[test]
+	fn refunded_on_cancel() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalDepositRefunded { proposal_id, who: 1, amount: 10 }.into(),
+			);
+			assert_eq!(Balances::reserved_balance(&1), 0);
+		});
+	}
+
+	#[test]
+	fn insufficient_balance() {
+		new_test_ext().execute_with(|| {
+			ProposalDeposit::set(10);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+			Balances::make_free_balance_be(&2, 5u32.into());
+
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(2),
+					sp_core::H256::repeat_byte(1),
+					5,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
+				pallet_balances::Error::<Test>::InsufficientBalance
+			);
+		});
+	}
+}
+
+mod deposit_slashing {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		ProposalDeposit::set(10);
+		Quorum::set(2);
+		for voter in 1..=2 {
+			Balances::make_free_balance_be(&voter, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(1)));
+
+		proposal_id
+	}
+
+	#[test]
+	fn slashed_without_quorum() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			let issuance = Balances::total_issuance();
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalDepositSlashed { proposal_id, who: 1, amount: 10 }.into(),
+			);
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Balances::free_balance(&1), 15);
+			assert_eq!(Balances::total_issuance(), issuance - 10);
+		});
+	}
+
+	#[test]
+	fn refunded_when_rejected_with_quorum() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Nay(1)));
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Rejected,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+			System::assert_has_event(
+				Event::ProposalDepositRefunded { proposal_id, who: 1, amount: 10 }.into(),
+			);
+			assert_eq!(Balances::free_balance(&1), 24);
+		});
+	}
+
+	fn make_unvoted_proposal() -> u32 {
+		System::set_block_number(1);
+		ProposalDeposit::set(10);
+		Balances::make_free_balance_be(&1, 25u32.into());
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		System::set_block_number(6);
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn expired_slashed_when_quorum_required() {
+		new_test_ext().execute_with(|| {
+			Quorum::set(1);
+			let proposal_id = make_unvoted_proposal();
+
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().status, ProposalStatus::Expired);
+			System::assert_has_event(
+				Event::ProposalDepositSlashed { proposal_id, who: 1, amount: 10 }.into(),
+			);
+			assert_eq!(Balances::free_balance(&1), 15);
+		});
+	}
+
+	#[test]
+	fn expired_refunded_without_quorum() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = make_unvoted_proposal();
+
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().status, ProposalStatus::Expired);
+			System::assert_has_event(
+				Event::ProposalDepositRefunded { proposal_id, who: 1, amount: 10 }.into(),
+			);
+			assert_eq!(Balances::free_balance(&1), 25);
+		});
+	}
+}
+
+mod migrations {
+	use super::*;
+	use crate::{
+		migrations::v1::{v0, MigrateToV1},
+		Proposals, Votes, STORAGE_VERSION,
+	};
+	use codec::Encode;
+	use frame_support::traits::{
+		NamedReservableCurrency, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+	};
+
+	#[test]
+	fn migrates_v0_entries() {
+		new_test_ext().execute_with(|| {
+			let old_proposal = v0::OldProposal::<Test> {
+				id: 1,
+				proposer: 1,
+				text: sp_core::H256::repeat_byte(1),
+				time_period: 50,
+				status: ProposalStatus::InProgress,
+				ayes: 3,
+				nays: 0,
+			};
+			let old_vote = v0::OldVote { vote_decision: VoteDecision::Aye(3), locked: true };
+			frame_support::storage::unhashed::put_raw(
+				&Proposals::<Test>::hashed_key_for(1u32),
+				&old_proposal.encode(),
+			);
+			frame_support::storage::unhashed::put_raw(
+				&Votes::<Test>::hashed_key_for(2u64, 1u32),
+				&old_vote.encode(),
+			);
+			Balances::make_free_balance_be(&2, 20);
+			assert_ok!(Balances::reserve(&2, 9));
+			assert_eq!(StorageVersion::get::<Voting>(), 0);
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Voting>(), 1);
+			let proposal = Voting::get_proposal(&1).unwrap();
+			assert_eq!(
+				(proposal.tally.ayes, proposal.tally.nays, proposal.tally.abstains),
+				(3, 0, 0)
+			);
+			assert_eq!((proposal.deposit, proposal.created_at), (0, 0));
+			assert_eq!((proposal.quadratic_ayes, proposal.quadratic_nays), (1, 0));
+			let vote = Voting::get_vote(&2, &1).unwrap();
+			assert_eq!((vote.multiplier, vote.quadratic_ayes, vote.quadratic_nays), (1, 1, 0));
+			assert_eq!(vote.reserved, 9);
+			assert_eq!(Voting::active_votes_of(&2), 1);
+			assert_eq!(Voting::total_locked(), 9);
+			assert_eq!(Voting::voter_turnout(&1), 1);
+			assert_eq!(Voting::voters_for(&1), vec![2]);
+			assert_eq!(Voting::count_with_status(&ProposalStatus::InProgress), 1);
+			assert_eq!(crate::ActiveProposalCount::<Test>::get(), Some(1));
+			assert_eq!(crate::ActiveProposalIds::<Test>::get().into_inner(), vec![(50, 1)]);
+
+			//Running it again doesn't touch the migrated entries
+			MigrateToV1::<Test>::on_runtime_upgrade();
+			assert_eq!(Voting::voter_turnout(&1), 1);
+		});
+	}
+
+	#[test]
+	fn moves_locked_vote_reserves_to_named_reserve() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(60);
+			let old_proposal = v0::OldProposal::<Test> {
+				id: 1,
+				proposer: 1,
+				text: sp_core::H256::repeat_byte(1),
+				time_period: 50,
+				status: ProposalStatus::Passed,
+				ayes: 3,
+				nays: 0,
+			};
+			frame_support::storage::unhashed::put_raw(
+				&Proposals::<Test>::hashed_key_for(1u32),
+				&old_proposal.encode(),
+			);
+			frame_support::storage::unhashed::put_raw(
+				&Votes::<Test>::hashed_key_for(2u64, 1u32),
+				&v0::OldVote { vote_decision: VoteDecision::Aye(3), locked: true }.encode(),
+			);
+			Balances::make_free_balance_be(&2, 20);
+			assert_ok!(Balances::reserve(&2, 9));
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+			assert_eq!(Balances::reserved_balance_named(&Voting::reserve_id(), &2), 9);
+			assert_eq!(Balances::reserved_balance(&2), 9);
+
+			//The migrated vote gives back the balance reserved before the upgrade
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(2), 1));
+			assert_eq!(Balances::reserved_balance(&2), 0);
+			assert_eq!(Balances::free_balance(&2), 20);
+			assert_eq!(Voting::total_locked(), 0);
+		});
+	}
+
+	#[test]
+	fn failed_named_reserve_is_not_counted() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(60);
+			let old_proposal = v0::OldProposal::<Test> {
+				id: 1,
+				proposer: 1,
+				text: sp_core::H256::repeat_byte(1),
+				time_period: 50,
+				status: ProposalStatus::Passed,
+				ayes: 3,
+				nays: 0,
+			};
+			frame_support::storage::unhashed::put_raw(
+				&Proposals::<Test>::hashed_key_for(1u32),
+				&old_proposal.encode(),
+			);
+			frame_support::storage::unhashed::put_raw(
+				&Votes::<Test>::hashed_key_for(2u64, 1u32),
+				&v0::OldVote { vote_decision: VoteDecision::Aye(3), locked: true }.encode(),
+			);
+			Balances::make_free_balance_be(&2, 20);
+			assert_ok!(Balances::reserve(&2, 9));
+			//The account has no named reserve left for the pallet
+			for id in 0..10u8 {
+				assert_ok!(Balances::reserve_named(&[id; 8], &2, 1));
+			}
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+			assert_eq!(Balances::reserved_balance_named(&Voting::reserve_id(), &2), 0);
+			assert_eq!(Balances::reserved_balance(&2), 10);
+			assert_eq!(Voting::get_vote(&2, &1).unwrap().reserved, 0);
+			assert_eq!(Voting::total_locked(), 0);
+		});
+	}
+
+	#[test]
+	fn upgrade_sets_on_chain_storage_version() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Voting::storage_version(), StorageVersion::new(0));
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+			assert_eq!(Voting::storage_version(), STORAGE_VERSION);
+		});
+	}
+}
+
+mod constants {
+	use super::*;
+
+	#[test]
+	fn config_values_in_metadata() {
+		let names: Vec<&str> = Voting::pallet_constants_metadata()
+			.iter()
+			.map(|constant| constant.name)
+			.collect();
+
+		for name in [
+			"VoteRemovalThreshold",
+			"MaxVoters",
+			"VoteLimit",
+			"MaxBatchSize",
+			"MaxProposalVotes",
+			"Quorum",
+			"ProposalCooldown",
+			"ProposalDeposit",
+			"MaxProposalDuration",
+			"UseQuadraticTally",
+			"MaxProposals",
+			"SnapshotInterval",
+			"MaxSnapshotsPerBlock",
+			"ConvictionPeriod",
+			"MaxProposalsPerProposer",
+			"MinVoteAmount",
+			"MaxCallSize",
+			"MaxTags",
+			"MaxTagLen",
+			"PostResultLock",
+			"PalletId",
+			"MaxVotesPerVoter",
+			"RevealPeriod",
+			"CommitmentDeposit",
+			"MaxExpiriesPerBlock",
+			"ProposerCanVote",
+			"MembershipDeposit",
+			"MaxDelegationsPerAccount",
+			"MinProposalDuration",
+			"CapacityWarnThreshold",
+			"MaxVotersPerProposal",
+		] {
+			assert!(names.contains(&name), "{} is not exposed as a constant", name);
+		}
+	}
+}
+
+mod integrity_test {
+	use super::*;
+	use frame_support::traits::Hooks;
+
+	#[test]
+	fn mock_config_is_valid() {
+		new_test_ext().execute_with(|| {
+			<Voting as Hooks<u64>>::integrity_test();
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "MinVoteAmount must not be greater than VoteLimit")]
+	fn min_vote_amount_above_limit() {
+		new_test_ext().execute_with(|| {
+			MinVoteAmount::set(VoteLimit::get() + 1);
+			<Voting as Hooks<u64>>::integrity_test();
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "MaxVoters must be greater than zero")]
+	fn no_voters_allowed() {
+		new_test_ext().execute_with(|| {
+			MaxVoters::set(0);
+			<Voting as Hooks<u64>>::integrity_test();
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "MaxVotersPerProposal must be greater than zero")]
+	fn no_voters_per_proposal_allowed() {
+		new_test_ext().execute_with(|| {
+			MaxVotersPerProposal::set(0);
+			<Voting as Hooks<u64>>::integrity_test();
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "MinProposalDuration must not be greater than MaxProposalDuration")]
+	fn min_proposal_duration_above_max() {
+		new_test_ext().execute_with(|| {
+			MinProposalDuration::set(MaxProposalDuration::get() + 1);
+			<Voting as Hooks<u64>>::integrity_test();
+		});
+	}
+}
+
+mod quadratic_tally {
+	use super::*;
+
+	//Linear tally: 4 ayes vs 3 nays. Quadratic tally: isqrt(4) = 2 vs 3 * isqrt(1) = 3.
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		for voter in 1..=4 {
+			Balances::make_free_balance_be(&voter, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(4)));
+		for voter in 2..=4 {
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(voter),
+				proposal_id,
+				VoteDecision::Nay(1)
+			));
+		}
+
+		let proposal = Voting::get_proposal(&proposal_id).unwrap();
+		assert_eq!((proposal.tally.ayes, proposal.tally.nays), (4, 3));
+		assert_eq!((proposal.quadratic_ayes, proposal.quadratic_nays), (2, 3));
+		System::set_block_number(6);
+
+		proposal_id
+	}
+
+	#[test]
+	fn linear_tally_passes() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Passed,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn quadratic_tally_rejects() {
+		new_test_ext().execute_with(|| {
+			UseQuadraticTally::set(true);
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_has_event(
+				Event::ProposalEnded {
+					proposal_id,
+					status: ProposalStatus::Rejected,
+					proposer_still_registered: true,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn update_moves_quadratic_power() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::set_block_number(1);
+
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDecision::Aye(4)
+			));
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.quadratic_ayes, proposal.quadratic_nays), (4, 2));
+		});
+	}
+
+	#[test]
+	fn no_rounding_drift() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::set_block_number(1);
+			VoteRemovalThreshold::set(0);
+			Balances::make_free_balance_be(&5, 50u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 5));
+			let before = Voting::get_proposal(&proposal_id).unwrap();
+
+			//isqrt(3) rounds down to 1
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(5), proposal_id, VoteDecision::Aye(3)));
+			let vote = Voting::get_vote(&5, &proposal_id).unwrap();
+			assert_eq!((vote.quadratic_ayes, vote.quadratic_nays), (1, 0));
+
+			for decision in
+				[VoteDecision::Nay(5), VoteDecision::Split { aye: 3, nay: 4 }, VoteDecision::Aye(2)]
+			{
+				assert_ok!(Voting::update_vote(RuntimeOrigin::signed(5), proposal_id, decision));
+			}
+			let vote = Voting::get_vote(&5, &proposal_id).unwrap();
+			assert_eq!((vote.quadratic_ayes, vote.quadratic_nays), (1, 0));
+
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(5), proposal_id));
+			let after = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(
+				(after.ayes, after.nays, after.quadratic_ayes, after.quadratic_nays),
+				(before.ayes, before.nays, before.quadratic_ayes, before.quadratic_nays)
+			);
+		});
+	}
+}
+
+mod switch_vote {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&1, 25u32.into());
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			50,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+
+		proposal_id
+	}
+
+	#[test]
+	fn flips_direction_keeping_balance() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_eq!(Balances::reserved_balance(&1), 9);
+
+			assert_ok!(Voting::switch_vote(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_last_event(
+				Event::VoteUpdated {
+					proposal_id,
+					who: 1,
+					previous: VoteDecision::Aye(3),
+					new: VoteDecision::Nay(3),
+					amount_delta: 0,
+					direction_changed: true,
+				}
+				.into(),
+			);
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.tally.ayes, proposal.tally.nays), (0, 3));
+			assert_eq!(Balances::reserved_balance(&1), 9);
+
+			assert_ok!(Voting::switch_vote(RuntimeOrigin::signed(1), proposal_id));
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.tally.ayes, proposal.tally.nays), (3, 0));
+			assert_eq!(Balances::reserved_balance(&1), 9);
+		});
+	}
+
+	#[test]
+	fn abstention_and_missing_vote() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_noop!(
+				Voting::switch_vote(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::VoteNotFound
+			);
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Abstain(2)
+			));
+			assert_noop!(
+				Voting::switch_vote(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::CannotSwitchAbstention
+			);
+		});
+	}
+
+	#[test]
+	fn ended_proposal() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+
+			System::set_block_number(50);
+			assert_noop!(
+				Voting::switch_vote(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+		});
+	}
+}
+
+mod max_proposals {
+	use super::*;
+
+	#[test]
+	fn creation_blocked_at_cap() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			MaxProposals::set(2);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for _ in 0..2 {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					5,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					5,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::TooManyProposals
+			);
+
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 2));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				10,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_eq!(crate::ActiveProposalCount::<Test>::get(), Some(2));
+		});
+	}
+}
+
+mod clean_up_proposal {
+	use super::*;
+	use crate::{Proposals, VotersByProposal, Votes};
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		for voter in 1..=2 {
+			Balances::make_free_balance_be(&voter, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(1)));
+
+		proposal_id
+	}
+
+	#[test]
+	fn purges_proposal_and_votes() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(2), proposal_id));
+
+			assert_ok!(Voting::clean_up_proposal(RuntimeOrigin::signed(3), proposal_id));
+			System::assert_last_event(Event::ProposalPurged { proposal_id }.into());
+
+			assert!(!Proposals::<Test>::contains_key(proposal_id));
+			assert!(!VotersByProposal::<Test>::contains_key(proposal_id));
+			assert_eq!(Votes::<Test>::iter().count(), 0);
+			assert_eq!(Voting::voter_turnout(&proposal_id), 0);
+		});
+	}
+
+	#[test]
+	fn locked_funds_block_purge() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_noop!(
+				Voting::clean_up_proposal(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ProposalInProgress
+			);
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id));
+			assert_noop!(
+				Voting::clean_up_proposal(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::CannotPurgeWithLockedFunds
+			);
+		});
+	}
+}
+
+mod vote_with_max_balance {
+	use super::*;
+	use crate::VoteDirection;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		for voter in 1..=3 {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		Balances::make_free_balance_be(&1, 100u32.into());
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn votes_with_exact_square() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			Balances::make_free_balance_be(&2, 25u32.into());
+
+			assert_ok!(Voting::vote_with_max_balance(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDirection::Aye
+			));
+			System::assert_last_event(
+				Event::VoteCasted { proposal_id, who: 2, vote_decision: VoteDecision::Aye(5) }
+					.into(),
+			);
+			assert_eq!(Balances::reserved_balance(&2), 25);
+		});
+	}
+
+	#[test]
+	fn floors_vote_amount() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			Balances::make_free_balance_be(&2, 35u32.into());
+
+			assert_ok!(Voting::vote_with_max_balance(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDirection::Nay
+			));
+			assert_eq!(
+				Voting::get_vote(&2, &proposal_id).map(|vote| vote.vote_decision),
+				Some(VoteDecision::Nay(5))
+			);
+			assert_eq!(Balances::reserved_balance(&2), 25);
+			assert_eq!(Balances::free_balance(&2), 10);
+		});
+	}
+
+	#[test]
+	fn capped_by_vote_limit() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			Balances::make_free_balance_be(&2, 10_000u32.into());
+
+			assert_ok!(Voting::vote_with_max_balance(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDirection::Aye
+			));
+			assert_eq!(
+				Voting::get_vote(&2, &proposal_id).map(|vote| vote.vote_decision),
+				Some(VoteDecision::Aye(VoteLimit::get()))
+			);
+		});
+	}
+
+	#[test]
+	fn unaffordable_vote() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_noop!(
+				Voting::vote_with_max_balance(
+					RuntimeOrigin::signed(3),
+					proposal_id,
+					VoteDirection::Aye
+				),
+				Error::<Test>::InvalidVoteAmount
+			);
+		});
+	}
+}
+
+mod tally_snapshots {
+	use super::*;
+	use crate::WeightInfo;
+	use frame_support::traits::Hooks;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		SnapshotInterval::set(5);
+		for voter in 1..=2 {
+			Balances::make_free_balance_be(&voter, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			20,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn snapshot_matches_live_tally() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(2)));
+
+			System::set_block_number(5);
+			Voting::on_initialize(5);
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!(
+				Voting::tally_at(proposal_id, 5),
+				Some((proposal.tally.ayes, proposal.tally.nays))
+			);
+
+			//Later votes don't change the recorded snapshot
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(4)
+			));
+			assert_eq!(Voting::tally_at(proposal_id, 5), Some((3, 2)));
+		});
+	}
+
+	#[test]
+	fn snapshots_only_at_interval() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			Voting::on_initialize(4);
+			assert_eq!(Voting::tally_at(proposal_id, 4), None);
+
+			SnapshotInterval::set(0);
+			Voting::on_initialize(5);
+			assert_eq!(Voting::tally_at(proposal_id, 5), None);
+		});
+	}
+
+	#[test]
+	fn snapshots_capped_per_block() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			MaxSnapshotsPerBlock::set(2);
+			for _ in 0..2 {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(2),
+					sp_core::H256::repeat_byte(1),
+					20,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+
+			Voting::on_initialize(5);
+			assert_eq!(crate::TallySnapshots::<Test>::iter().count(), 2);
+			assert_eq!(Voting::tally_at(3, 5), None);
+
+			//The next snapshot block starts with the proposal left out and wraps around
+			Voting::on_initialize(10);
+			assert_eq!(Voting::tally_at(3, 10), Some((0, 0)));
+			assert_eq!(Voting::tally_at(1, 10), Some((0, 0)));
+			assert_eq!(Voting::tally_at(2, 10), None);
+		});
+	}
+
+	#[test]
+	fn charges_every_proposal_read() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			//An entry whose proposal is gone is read without being snapshotted
+			crate::ActiveProposalIds::<Test>::mutate(|ids| ids.try_push((20, 99)).unwrap());
+
+			let weight = Voting::on_initialize(5);
+			assert_eq!(crate::TallySnapshots::<Test>::iter().count(), 1);
+			assert_eq!(weight, <() as WeightInfo>::snapshot_tallies(2));
+		});
+	}
+}
+
+mod conviction {
+	use super::*;
+	use crate::Conviction;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		for voter in 1..=2 {
+			Balances::make_free_balance_be(&voter, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn conviction_multiplies_tally() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::vote_with_conviction(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(3),
+				Conviction::Locked2x
+			));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(3)));
+
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.tally.ayes, proposal.tally.nays), (6, 3));
+			//The reserve is still the square of the points
+			assert_eq!(Balances::reserved_balance(&1), 9);
+			assert_eq!(
+				Voting::get_vote(&1, &proposal_id).unwrap().conviction,
+				Conviction::Locked2x
+			);
+		});
+	}
+
+	#[test]
+	fn conviction_blocks_early_unlock() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote_with_conviction(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(3),
+				Conviction::Locked2x
+			));
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+			//Locked for two conviction periods after the end of the proposal
+			let unlock_block = 5 + 2 * ConvictionPeriod::get();
+			System::set_block_number(unlock_block - 1);
+			assert_noop!(
+				Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ConvictionLocked
+			);
+
+			System::set_block_number(unlock_block);
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Balances::reserved_balance(&1), 0);
+		});
+	}
+
+	#[test]
+	fn canceled_proposal_releases_conviction() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote_with_conviction(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				VoteDecision::Nay(2),
+				Conviction::Locked6x
+			));
+
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Balances::reserved_balance(&2), 0);
+		});
+	}
+}
+
+mod proposals_by_proposer {
+	use super::*;
+
+	fn make_proposal(who: u64) -> u32 {
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(who),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn indexes_proposals_of_proposer() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			for voter in 1..=2 {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+
+			let first = make_proposal(1);
+			let other = make_proposal(2);
+			let second = make_proposal(1);
+
+			assert_eq!(Voting::proposals_of(&1), vec![first, second]);
+			assert_eq!(Voting::proposals_of(&2), vec![other]);
+			assert!(Voting::proposals_of(&3).is_empty());
+		});
+	}
+
+	#[test]
+	fn proposer_limit_reached() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			MaxProposalsPerProposer::set(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			make_proposal(1);
+
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					5,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::TooManyProposalsForProposer
+			);
+		});
+	}
+
+	#[test]
+	fn purged_proposals_leave_index() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			let proposal_id = make_proposal(1);
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+			assert_ok!(Voting::clean_up_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert!(Voting::proposals_of(&1).is_empty());
+		});
+	}
+}
+
+mod vote_decision {
+	use super::*;
+	use crate::VoteDirection;
+
+	#[test]
+	fn accessors() {
+		let aye = VoteDecision::Aye(3);
+		assert_eq!(
+			(aye.amount(), aye.is_aye(), aye.direction()),
+			(3, true, Some(VoteDirection::Aye))
+		);
+
+		let nay = VoteDecision::Nay(2);
+		assert_eq!(
+			(nay.amount(), nay.is_aye(), nay.direction()),
+			(2, false, Some(VoteDirection::Nay))
+		);
+
+		let abstain = VoteDecision::Abstain(5);
+		assert_eq!(
+			(abstain.amount(), abstain.is_aye(), abstain.direction()),
+			(5, false, Some(VoteDirection::Abstain))
+		);
+
+		let split = VoteDecision::Split { aye: 2, nay: 3 };
+		assert_eq!((split.amount(), split.is_aye(), split.direction()), (5, false, None));
+	}
+}
+
+mod finished_proposals {
+	use super::*;
+
+	fn finished_ids(start: u32, limit: u32) -> Vec<u32> {
+		Voting::finished_proposals(start, limit).into_iter().map(|(id, _)| id).collect()
+	}
+
+	#[test]
+	fn only_finished_proposals_returned() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for time_period in [5, 50, 5, 50, 5] {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					time_period,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 4));
+
+			System::set_block_number(6);
+			for proposal_id in [1, 3] {
+				assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			}
+
+			assert_eq!(finished_ids(1, 10), vec![1, 3, 4]);
+			assert_eq!(finished_ids(2, 10), vec![3, 4]);
+			assert_eq!(finished_ids(1, 2), vec![1, 3]);
+			assert!(finished_ids(5, 10).is_empty());
+		});
+	}
+}
+
+mod active_proposals {
+	use super::*;
+
+	fn active_ids(start_after: Option<u32>, limit: u32) -> Vec<u32> {
+		Voting::active_proposals(start_after, limit)
+			.into_iter()
+			.map(|(id, _)| id)
+			.collect()
+	}
+
+	#[test]
+	fn paginates_votable_proposals() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for time_period in [50, 5, 50, 50, 50, 50] {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					time_period,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 4));
+
+			//Proposal 2 expired without being finished
+			System::set_block_number(6);
+
+			assert_eq!(active_ids(None, 10), vec![1, 3, 5, 6]);
+			assert_eq!(active_ids(None, 2), vec![1, 3]);
+			assert_eq!(active_ids(Some(3), 2), vec![5, 6]);
+			assert!(active_ids(Some(6), 2).is_empty());
+		});
+	}
+
+	#[test]
+	fn page_is_capped() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			for proposal_id in 1..=crate::MAX_PAGE_SIZE + 1 {
+				crate::Proposals::<Test>::insert(
+					proposal_id,
+					Proposal::<Test>::new(
+						proposal_id,
+						1,
+						sp_core::H256::repeat_byte(1),
+						50,
+						0,
+						None,
+						None,
+					),
+				);
+			}
+			crate::ProposalCounter::<Test>::put(crate::MAX_PAGE_SIZE + 1);
+
+			let page = active_ids(None, u32::MAX);
+			assert_eq!(page.len() as u32, crate::MAX_PAGE_SIZE);
+			assert_eq!(active_ids(page.last().copied(), u32::MAX), vec![crate::MAX_PAGE_SIZE + 1]);
+		});
+	}
+}
+
+mod split_vote {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		Balances::make_free_balance_be(&1, 50u32.into());
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			90,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn split_vote_tallies_both_sides() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Split { aye: 2, nay: 3 }
+			));
+
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.tally.ayes, proposal.tally.nays), (2, 3));
+			assert_eq!((proposal.quadratic_ayes, proposal.quadratic_nays), (1, 1));
+			//(aye + nay)^2
+			assert_eq!(Balances::reserved_balance(&1), 25);
+			assert_eq!(Voting::voter_turnout(&proposal_id), 1);
+		});
+	}
+
+	#[test]
+	fn update_split_vote() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Split { aye: 2, nay: 3 }
+			));
+
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Split { aye: 4, nay: 1 }
+			));
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.tally.ayes, proposal.tally.nays), (4, 1));
+			assert_eq!(Balances::reserved_balance(&1), 25);
+
+			assert_ok!(Voting::switch_vote(RuntimeOrigin::signed(1), proposal_id));
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.tally.ayes, proposal.tally.nays), (1, 4));
+		});
+	}
+
+	#[test]
+	fn split_vote_over_limit() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_noop!(
+				Voting::vote(
+					RuntimeOrigin::signed(1),
+					proposal_id,
+					VoteDecision::Split { aye: VoteLimit::get(), nay: 1 }
+				),
+				Error::<Test>::VoteAmountLimit
+			);
+		});
+	}
+}
+
+mod winner_margin {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		for voter in 1..=2 {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			Balances::make_free_balance_be(&voter, 50u32.into());
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			10,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn passing_proposal() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(5)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(2)));
+
+			assert_eq!(Voting::winner_margin(&proposal_id), Some((true, 3)));
+		});
+	}
+
+	#[test]
+	fn failing_proposal() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(4)));
+
+			assert_eq!(Voting::winner_margin(&proposal_id), Some((false, 3)));
+		});
+	}
+
+	#[test]
+	fn tied_proposal() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_eq!(Voting::winner_margin(&proposal_id), Some((true, 0)));
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(3)));
+			assert_eq!(Voting::winner_margin(&proposal_id), Some((true, 0)));
+		});
+	}
+
+	#[test]
+	fn nonexistent_proposal() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Voting::winner_margin(&1), None);
+		});
+	}
+}
+
+mod force_register_voter {
+	use super::*;
+
+	#[test]
+	fn registers_past_max_voters() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			MaxVoters::set(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_noop!(
+				Voting::register_voter(RuntimeOrigin::root(), 2),
+				Error::<Test>::MaxVotersLimitReached
+			);
+
+			assert_ok!(Voting::force_register_voter(RuntimeOrigin::root(), 2));
+			assert!(Voting::is_registered(&2));
+			assert_eq!(Voting::voter_count(), 2);
+			System::assert_last_event(Event::VoterRegistered { who: 2, total: 2 }.into());
+		});
+	}
+
+	#[test]
+	fn voters_past_max_voters_can_vote() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			MaxVoters::set(1);
+			MaxVotersPerProposal::set(2);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for voter in 2..=3 {
+				assert_ok!(Voting::force_register_voter(RuntimeOrigin::root(), voter));
+			}
+			for voter in 1..=3 {
+				Balances::make_free_balance_be(&voter, 25u32.into());
+			}
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				10,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(1)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 1, VoteDecision::Aye(1)));
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(3), 1, VoteDecision::Aye(1)),
+				Error::<Test>::TooManyVotersOnProposal
+			);
+		});
+	}
+
+	#[test]
+	fn already_registered() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_noop!(
+				Voting::force_register_voter(RuntimeOrigin::root(), 1),
+				Error::<Test>::AlreadyRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn requires_root() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Voting::force_register_voter(RuntimeOrigin::signed(1), 2),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+	}
+}
+
+mod paused {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		Balances::make_free_balance_be(&1, 50u32.into());
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			90,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn gated_calls_fail_while_paused() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+
+			assert_ok!(Voting::set_paused(RuntimeOrigin::root(), true));
+			System::assert_last_event(Event::PausedStateChanged { paused: true }.into());
+
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(2),
+					90,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				),
+				Error::<Test>::Paused
+			);
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)),
+				Error::<Test>::Paused
+			);
+			assert_noop!(
+				Voting::update_vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)),
+				Error::<Test>::Paused
+			);
+			assert_noop!(
+				Voting::switch_vote(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::Paused
+			);
+
+			assert_ok!(Voting::set_paused(RuntimeOrigin::root(), false));
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(3)
+			));
+		});
+	}
+
+	#[test]
+	fn funds_can_be_returned_while_paused() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_ok!(Voting::force_finish_proposal(RuntimeOrigin::root(), proposal_id));
+
+			assert_ok!(Voting::set_paused(RuntimeOrigin::root(), true));
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Balances::reserved_balance(&1), 0);
+		});
+	}
+
+	#[test]
+	fn cancel_vote_while_paused() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+
+			assert_ok!(Voting::set_paused(RuntimeOrigin::root(), true));
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Balances::reserved_balance(&1), 0);
+		});
+	}
+
+	#[test]
+	fn requires_root() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Voting::set_paused(RuntimeOrigin::signed(1), true),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+	}
+}
+
+mod enactment {
+	use super::*;
+
+	fn remark(len: usize) -> Box<RuntimeCall> {
+		Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![1; len] }))
+	}
+
+	fn before_each(call: Option<Box<RuntimeCall>>) -> u32 {
+		System::set_block_number(1);
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		Balances::make_free_balance_be(&1, 25u32.into());
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			5,
+			None,
+			None,
+			call,
+			vec![],
+			None
+		));
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn passed_proposal_dispatches_call() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each(Some(remark(4)));
+			assert!(Voting::get_proposal(&proposal_id).unwrap().call_hash.is_some());
+			assert!(crate::EnactmentCalls::<Test>::contains_key(proposal_id));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			System::assert_last_event(
+				Event::ProposalEnacted { proposal_id, result: Ok(()) }.into(),
+			);
+			assert!(!crate::EnactmentCalls::<Test>::contains_key(proposal_id));
+		});
+	}
+
+	#[test]
+	fn rejected_proposal_does_not_dispatch_call() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each(Some(remark(4)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Nay(1)));
+
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::Voting(Event::ProposalEnacted { .. })
+			)));
+			assert!(!crate::EnactmentCalls::<Test>::contains_key(proposal_id));
+		});
+	}
+
+	#[test]
+	fn call_too_large() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_noop!(
+				Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					5,
+					None,
+					None,
+					Some(remark(MaxCallSize::get() as usize)),
+					vec![],
+					None
+				),
+				Error::<Test>::CallTooLarge
+			);
+		});
+	}
+
+	#[test]
+	fn canceled_proposal_drops_call() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each(Some(remark(4)));
+
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert!(!crate::EnactmentCalls::<Test>::contains_key(proposal_id));
+		});
+	}
+}
+
+mod transfer_proposal_ownership {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		for voter in 1..=2 {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			Balances::make_free_balance_be(&voter, 25u32.into());
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			10,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		Voting::get_proposal_counter()
+	}
+
+	#[test]
+	fn new_owner_takes_control() {
+		new_test_ext().execute_with(|| {
+			ProposalDeposit::set(5);
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::transfer_proposal_ownership(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				2
+			));
+			System::assert_last_event(
+				Event::ProposalOwnershipTransferred { proposal_id, from: 1, to: 2 }.into(),
+			);
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().proposer, 2);
+			assert_eq!(Voting::proposals_of(&1), Vec::<u32>::new());
+			assert_eq!(Voting::proposals_of(&2), vec![proposal_id]);
+			assert_eq!((Balances::reserved_balance(&1), Balances::reserved_balance(&2)), (0, 5));
+
+			assert_noop!(
+				Voting::increase_proposal_time(RuntimeOrigin::signed(1), proposal_id, 20),
+				Error::<Test>::Unauthorized
+			);
+			assert_ok!(Voting::increase_proposal_time(RuntimeOrigin::signed(2), proposal_id, 20));
+		});
+	}
+
+	#[test]
+	fn only_proposer_can_transfer() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_noop!(
+				Voting::transfer_proposal_ownership(RuntimeOrigin::signed(2), proposal_id, 2),
+				Error::<Test>::Unauthorized
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_to_self() {
+		new_test_ext().execute_with(|| {
+			ProposalDeposit::set(5);
+			let proposal_id = before_each();
+
+			assert_noop!(
+				Voting::transfer_proposal_ownership(RuntimeOrigin::signed(1), proposal_id, 1),
+				Error::<Test>::AlreadyProposer
+			);
+			assert_eq!(Voting::proposals_of(&1), vec![proposal_id]);
+			assert_eq!(Balances::reserved_balance(&1), 5);
+		});
+	}
+
+	#[test]
+	fn new_owner_must_be_registered() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_noop!(
+				Voting::transfer_proposal_ownership(RuntimeOrigin::signed(1), proposal_id, 3),
+				Error::<Test>::VoterIsNotRegistered
+			);
+		});
+	}
+
+	#[test]
+	fn ended_proposal() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+			assert_noop!(
+				Voting::transfer_proposal_ownership(RuntimeOrigin::signed(1), proposal_id, 2),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+		});
+	}
+}
+
+mod on_idle {
+	use super::*;
+	use crate::WeightInfo;
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	fn make_expired_proposals(count: u64) {
+		System::set_block_number(1);
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		for i in 0..count {
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5 + i,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+		}
+		System::set_block_number(10);
+	}
+
+	fn in_progress() -> usize {
+		crate::Proposals::<Test>::iter_values()
+			.filter(|proposal| proposal.status == ProposalStatus::InProgress)
+			.count()
+	}
+
+	#[test]
+	fn finishes_as_many_proposals_as_the_budget_allows() {
+		new_test_ext().execute_with(|| {
+			make_expired_proposals(3);
+			let finish = <() as WeightInfo>::finish_proposal();
+
+			let used = Voting::on_idle(10, finish.saturating_mul(2));
+			assert_eq!(used, finish.saturating_mul(2));
+			assert_eq!(in_progress(), 1);
+
+			System::set_block_number(11);
+			Voting::on_idle(11, Weight::MAX);
+			assert_eq!(in_progress(), 0);
+			assert_eq!(Voting::count_with_status(&ProposalStatus::Expired), 3);
+		});
+	}
+
+	#[test]
+	fn no_budget_finishes_nothing() {
+		new_test_ext().execute_with(|| {
+			make_expired_proposals(2);
+
+			assert_eq!(Voting::on_idle(10, Weight::zero()), Weight::zero());
+			assert_eq!(in_progress(), 2);
+		});
+	}
+
+	#[test]
+	fn ongoing_proposals_are_left_alone() {
+		new_test_ext().execute_with(|| {
+			make_expired_proposals(1);
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				50,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+
+			Voting::on_idle(10, Weight::MAX);
+			assert_eq!(in_progress(), 1);
+			assert!(Voting::is_proposal_active(&Voting::get_proposal(&2).unwrap()));
+		});
+	}
+
+	#[test]
+	fn drains_expiries_over_the_per_block_cap_across_blocks() {
+		new_test_ext().execute_with(|| {
+			MaxExpiriesPerBlock::set(2);
+			make_expired_proposals(5);
+			let finish = <() as WeightInfo>::finish_proposal();
+
+			let used = Voting::on_idle(10, Weight::MAX);
+			assert_eq!(used, finish.saturating_mul(2));
+			assert_eq!(in_progress(), 3);
+			assert_eq!(crate::NextExpiryCursor::<Test>::get(), Some((6, 2)));
+
+			System::set_block_number(11);
+			Voting::on_idle(11, Weight::MAX);
+			assert_eq!(in_progress(), 1);
+
+			System::set_block_number(12);
+			Voting::on_idle(12, Weight::MAX);
+			assert_eq!(in_progress(), 0);
+			assert_eq!(crate::NextExpiryCursor::<Test>::get(), Some((9, 5)));
+			assert_eq!(Voting::count_with_status(&ProposalStatus::Expired), 5);
+		});
+	}
+
+	#[test]
+	fn resumes_after_the_cursor_and_wraps_around() {
+		new_test_ext().execute_with(|| {
+			MaxExpiriesPerBlock::set(1);
+			make_expired_proposals(3);
+			crate::NextExpiryCursor::<Test>::put((6, 2));
+
+			Voting::on_idle(10, Weight::MAX);
+			assert_eq!(Voting::get_proposal(&3).unwrap().status, ProposalStatus::Expired);
+			assert_eq!(in_progress(), 2);
+
+			//Past the last expired proposal the walk starts over from the earliest one
+			System::set_block_number(11);
+			Voting::on_idle(11, Weight::MAX);
+			assert_eq!(Voting::get_proposal(&1).unwrap().status, ProposalStatus::Expired);
+			assert_eq!(crate::NextExpiryCursor::<Test>::get(), Some((5, 1)));
+		});
+	}
+
+	#[test]
+	fn oversized_calls_do_not_hold_back_the_rest() {
+		new_test_ext().execute_with(|| {
+			MaxExpiriesPerBlock::set(1);
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			//Enacting the call doesn't fit in the weight of a single expiry
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				5,
+				None,
+				None,
+				Some(Box::new(RuntimeCall::Voting(crate::Call::set_paused { paused: false }))),
+				vec![],
+				None
+			));
+			for end in 6..=7 {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					end,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+
+			System::set_block_number(10);
+			Voting::on_idle(10, Weight::MAX);
+			assert_eq!(Voting::get_proposal(&2).unwrap().status, ProposalStatus::Expired);
+			assert_eq!(crate::NextExpiryCursor::<Test>::get(), Some((6, 2)));
+
+			System::set_block_number(11);
+			Voting::on_idle(11, Weight::MAX);
+			assert_eq!(Voting::get_proposal(&3).unwrap().status, ProposalStatus::Expired);
+			assert_eq!(Voting::get_proposal(&1).unwrap().status, ProposalStatus::InProgress);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 1));
+		});
+	}
+
+	#[test]
+	fn proposals_awaiting_reveals_do_not_hold_back_the_rest() {
+		new_test_ext().execute_with(|| {
+			Balances::make_free_balance_be(&1, 25u32.into());
+			make_expired_proposals(2);
+			System::set_block_number(1);
+			assert_ok!(Voting::commit_vote(
+				RuntimeOrigin::signed(1),
+				1,
+				Voting::commitment_of(&1, &1, &VoteDecision::Aye(1), &[0; 32])
+			));
+			System::set_block_number(10);
+
+			Voting::on_idle(10, Weight::MAX);
+			assert_eq!(Voting::get_proposal(&1).unwrap().status, ProposalStatus::InProgress);
+			assert_eq!(Voting::get_proposal(&2).unwrap().status, ProposalStatus::Expired);
+		});
+	}
+}
+
+mod tags {
+	use super::*;
+	use frame_support::BoundedVec;
+	use sp_runtime::DispatchResult;
+
+	fn make_tagged_proposal(tags: &[&str]) -> DispatchResult {
+		Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			10,
+			None,
+			None,
+			None,
+			tags.iter().map(|tag| tag.as_bytes().to_vec()).collect(),
+			None,
+		)
+	}
+
+	#[test]
+	fn query_proposals_by_tag() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(make_tagged_proposal(&["treasury", "technical"]));
+			assert_ok!(make_tagged_proposal(&["treasury"]));
+			assert_ok!(make_tagged_proposal(&["technical", "runtime"]));
+
+			assert_eq!(Voting::proposals_with_tag(b"treasury"), vec![1, 2]);
+			assert_eq!(Voting::proposals_with_tag(b"technical"), vec![1, 3]);
+			assert_eq!(Voting::proposals_with_tag(b"runtime"), vec![3]);
+			assert_eq!(Voting::proposals_with_tag(b"unknown"), Vec::<u32>::new());
+			assert_eq!(Voting::get_proposal(&2).unwrap().tags.len(), 1);
+		});
+	}
+
+	#[test]
+	fn invalid_tags() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+
+			assert_noop!(make_tagged_proposal(&[""]), Error::<Test>::EmptyTag);
+			assert_noop!(make_tagged_proposal(&["treasury", ""]), Error::<Test>::EmptyTag);
+			assert_noop!(make_tagged_proposal(&["abcdefghijklmnopq"]), Error::<Test>::TagTooLong);
+			assert_noop!(make_tagged_proposal(&["a", "b", "c", "d"]), Error::<Test>::TooManyTags);
+		});
+	}
+
+	#[test]
+	fn ended_proposals_leave_their_tags() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(make_tagged_proposal(&["treasury"]));
+			assert_ok!(make_tagged_proposal(&["treasury"]));
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 1));
+			assert_eq!(Voting::proposals_with_tag(b"treasury"), vec![2]);
+
+			assert_ok!(Voting::clean_up_proposal(RuntimeOrigin::signed(1), 1));
+			assert_eq!(Voting::proposals_with_tag(b"treasury"), vec![2]);
+		});
+	}
+
+	#[test]
+	fn reopening_fails_when_the_tag_is_full() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			MaxProposals::set(2);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(make_tagged_proposal(&["treasury"]));
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 1));
+			let tag: crate::TagOf<Test> = b"treasury".to_vec().try_into().unwrap();
+			let full = BoundedVec::<u32, MaxProposals>::truncate_from(vec![8, 9]);
+			crate::ProposalsByTag::<Test>::insert(&tag, full);
+
+			//The proposal stays canceled rather than in progress without being listed
+			assert_noop!(
+				Voting::reopen_proposal(RuntimeOrigin::root(), 1, 20),
+				Error::<Test>::TooManyProposalsWithTag
+			);
+		});
+	}
+
+	#[test]
+	fn ended_proposals_do_not_exhaust_a_tag() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			MaxProposals::set(2);
+			for proposal_id in 1..=3 {
+				assert_ok!(make_tagged_proposal(&["treasury"]));
+				assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+			}
+
+			assert_ok!(make_tagged_proposal(&["treasury"]));
+			assert_eq!(Voting::proposals_with_tag(b"treasury"), vec![4]);
+		});
+	}
+}
+
+mod simulate_vote {
+	use super::*;
+
+	#[test]
+	fn aye_flips_losing_proposal() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			for voter in 1..=2 {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+				Balances::make_free_balance_be(&voter, 25u32.into());
+			}
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				10,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			let proposal_id = Voting::get_proposal_counter();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(2)));
+			assert_eq!(
+				Voting::project_status(&Voting::get_proposal(&proposal_id).unwrap()),
+				ProposalStatus::Rejected
+			);
+
+			assert_eq!(
+				Voting::simulate_vote(&proposal_id, &VoteDecision::Aye(3)),
+				Some(ProposalStatus::Passed)
+			);
+			assert_eq!(
+				Voting::simulate_vote(&proposal_id, &VoteDecision::Aye(1)),
+				Some(ProposalStatus::Rejected)
+			);
+
+			//Nothing was persisted
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert_eq!((proposal.tally.ayes, proposal.tally.nays), (0, 2));
+			assert_eq!(Voting::voter_turnout(&proposal_id), 1);
+			assert_eq!(Balances::reserved_balance(&1), 0);
+		});
+	}
+
+	#[test]
+	fn nonexistent_proposal() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Voting::simulate_vote(&1, &VoteDecision::Aye(1)), None);
+		});
+	}
+}
+
+mod participation_rate {
+	use super::*;
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn one_of_four_voters() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			for voter in 1..=4 {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				10,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			let proposal_id = Voting::get_proposal_counter();
+			assert_eq!(Voting::participation_rate(&proposal_id), Some(Perbill::zero()));
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)));
+			assert_eq!(Voting::participation_rate(&proposal_id), Some(Perbill::from_percent(25)));
+		});
+	}
+
+	#[test]
+	fn no_registered_voters() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			crate::Proposals::<Test>::insert(
+				1,
+				Proposal::<Test>::new(1, 1, sp_core::H256::repeat_byte(1), 10, 0, None, None),
+			);
+
+			assert_eq!(Voting::participation_rate(&1), Some(Perbill::zero()));
+		});
+	}
+
+	#[test]
+	fn nonexistent_proposal() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Voting::participation_rate(&1), None);
+		});
+	}
+}
+
+mod vote_split {
+	use super::*;
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn splits_ayes_and_nays() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			for voter in 1..=2 {
+				Balances::make_free_balance_be(&voter, 25u32.into());
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				10,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			let proposal_id = Voting::get_proposal_counter();
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(1)));
+			assert_eq!(
+				Voting::vote_split(&proposal_id),
+				Some((Perbill::from_percent(75), Perbill::from_percent(25)))
+			);
+		});
+	}
+
+	#[test]
+	fn abstentions_are_left_aside() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let mut proposal =
+				Proposal::<Test>::new(1, 1, sp_core::H256::repeat_byte(1), 10, 0, None, None);
+			proposal.tally.nays = 2;
+			proposal.tally.abstains = 6;
+			crate::Proposals::<Test>::insert(1, proposal);
+
+			assert_eq!(Voting::vote_split(&1), Some((Perbill::zero(), Perbill::one())));
+		});
+	}
+
+	#[test]
+	fn no_votes() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			crate::Proposals::<Test>::insert(
+				1,
+				Proposal::<Test>::new(1, 1, sp_core::H256::repeat_byte(1), 10, 0, None, None),
+			);
+
+			assert_eq!(Voting::vote_split(&1), Some((Perbill::zero(), Perbill::zero())));
+		});
+	}
+
+	#[test]
+	fn nonexistent_proposal() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Voting::vote_split(&1), None);
+		});
+	}
+}
+
+mod bulk_finish_proposals {
+	use super::*;
+	use frame_support::BoundedVec;
+
+	#[test]
+	fn finishes_only_expired_proposals() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for time_period in [5, 6, 7, 50] {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					time_period,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			System::set_block_number(10);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 3));
+			System::reset_events();
+
+			//Already ended, still running and unknown proposals are skipped
+			let proposal_ids: BoundedVec<u32, MaxBatchSize> =
+				vec![1, 2, 3, 4, 9].try_into().unwrap();
+			assert_ok!(Voting::bulk_finish_proposals(RuntimeOrigin::signed(2), proposal_ids));
+
+			for proposal_id in [1, 2] {
+				System::assert_has_event(
+					Event::ProposalEnded {
+						proposal_id,
+						status: ProposalStatus::Expired,
+						proposer_still_registered: true,
+					}
+					.into(),
+				);
+			}
+			let ended = System::events()
+				.into_iter()
+				.filter(|record| {
+					matches!(record.event, RuntimeEvent::Voting(Event::ProposalEnded { .. }))
+				})
+				.count();
+			assert_eq!(ended, 2);
+			assert!(Voting::is_proposal_active(&Voting::get_proposal(&4).unwrap()));
+			assert_eq!(Voting::count_with_status(&ProposalStatus::Expired), 3);
+		});
+	}
+}
+
+mod max_votes_per_voter {
+	use super::*;
+
+	fn make_proposals(time_periods: &[u64]) {
+		System::set_block_number(1);
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		Balances::make_free_balance_be(&1, 100u32.into());
+		for time_period in time_periods {
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				*time_period,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+		}
+	}
+
+	#[test]
+	fn cancel_vote_frees_a_slot() {
+		new_test_ext().execute_with(|| {
+			MaxVotesPerVoter::set(2);
+			make_proposals(&[50, 50, 50]);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(1)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 2, VoteDecision::Aye(1)));
+			assert_eq!(Voting::active_votes_of(&1), 2);
+
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(1), 3, VoteDecision::Aye(1)),
+				Error::<Test>::TooManyActiveVotes
+			);
+
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), 1));
+			assert_eq!(Voting::active_votes_of(&1), 1);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 3, VoteDecision::Aye(1)));
+			assert_eq!(Voting::active_votes_of(&1), 2);
+		});
+	}
+
+	#[test]
+	fn unlock_and_canceled_proposals_free_slots() {
+		new_test_ext().execute_with(|| {
+			MaxVotesPerVoter::set(2);
+			make_proposals(&[5, 50, 50]);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 1, VoteDecision::Aye(1)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), 2, VoteDecision::Aye(1)));
+
+			//The vote stays counted until its balance is unlocked
+			System::set_block_number(6);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 1));
+			assert_eq!(Voting::active_votes_of(&1), 2);
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), 1));
+			assert_eq!(Voting::active_votes_of(&1), 1);
+
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 2));
+			assert_eq!(Voting::active_votes_of(&1), 0);
+			assert!(!crate::ActiveVotes::<Test>::contains_key(1));
+			assert_ok!(Voting::do_try_state());
+		});
+	}
+}
+
+mod pending_expiries {
+	use super::*;
+	use frame_support::traits::Hooks;
+
+	fn pending_expiries() -> Vec<(u32, u32)> {
+		System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::Voting(Event::PendingExpiries { due_this_block, total_active }) =>
+					Some((due_this_block, total_active)),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn emitted_on_the_end_block() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for time_period in [5, 5, 8] {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					time_period,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+
+			System::set_block_number(4);
+			Voting::on_finalize(4);
+			assert!(pending_expiries().is_empty());
+
+			System::set_block_number(5);
+			Voting::on_finalize(5);
+			assert_eq!(pending_expiries(), vec![(2, 3)]);
+			assert!(!crate::ExpiringAt::<Test>::contains_key(5));
+		});
+	}
+
+	#[test]
+	fn follows_extended_and_finished_proposals() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for _ in 0..2 {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					5,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			assert_ok!(Voting::increase_proposal_time(RuntimeOrigin::signed(1), 1, 9));
+			assert_ok!(Voting::force_finish_proposal(RuntimeOrigin::root(), 2));
+
+			System::set_block_number(5);
+			Voting::on_finalize(5);
+			assert!(pending_expiries().is_empty());
+
+			System::set_block_number(9);
+			Voting::on_finalize(9);
+			assert_eq!(pending_expiries(), vec![(1, 1)]);
+		});
+	}
+}
+
+mod reopen_proposal {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		Balances::make_free_balance_be(&1, 25u32.into());
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			20,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+		proposal_id
+	}
+
+	#[test]
+	fn reopens_canceled_proposal() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+			System::set_block_number(5);
+			assert_ok!(Voting::reopen_proposal(RuntimeOrigin::root(), proposal_id, 30));
+			System::assert_last_event(
+				Event::ProposalReopened { proposal_id, end_block: 30 }.into(),
+			);
+
+			//The proposal starts over without the votes settled by the cancellation
+			let proposal = Voting::get_proposal(&proposal_id).unwrap();
+			assert!(Voting::is_proposal_active(&proposal));
+			assert_eq!((proposal.tally.ayes, proposal.ended_at), (0, None));
+			assert_eq!(Voting::active_proposal_count(), 1);
+			assert_eq!(Voting::count_with_status(&ProposalStatus::Canceled), 0);
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_ok!(Voting::do_try_state());
+		});
+	}
+
+	#[test]
+	fn finished_proposal_cant_be_reopened() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::set_block_number(21);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+			assert_noop!(
+				Voting::reopen_proposal(RuntimeOrigin::root(), proposal_id, 30),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+		});
+	}
+
+	#[test]
+	fn validates_origin_status_and_deadline() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_noop!(
+				Voting::reopen_proposal(RuntimeOrigin::root(), proposal_id, 30),
+				Error::<Test>::ProposalInProgress
+			);
+
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_noop!(
+				Voting::reopen_proposal(RuntimeOrigin::signed(1), proposal_id, 30),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Voting::reopen_proposal(RuntimeOrigin::root(), proposal_id, 1),
+				Error::<Test>::TimePeriodToLow
+			);
+		});
+	}
+
+	#[test]
+	fn validates_new_duration() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+			MinProposalDuration::set(5);
+			MaxProposalDuration::set(100);
+
+			assert_noop!(
+				Voting::reopen_proposal(RuntimeOrigin::root(), proposal_id, 5),
+				Error::<Test>::ProposalDurationTooShort
+			);
+			assert_noop!(
+				Voting::reopen_proposal(RuntimeOrigin::root(), proposal_id, 102),
+				Error::<Test>::ProposalDurationTooLong
+			);
+			assert_ok!(Voting::reopen_proposal(RuntimeOrigin::root(), proposal_id, 6));
+		});
+	}
+
+	#[test]
+	fn removal_threshold_fits_new_duration() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				20,
+				None,
+				None,
+				None,
+				vec![],
+				Some(15)
+			));
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 1));
+
+			System::set_block_number(5);
+			assert_noop!(
+				Voting::reopen_proposal(RuntimeOrigin::root(), 1, 15),
+				Error::<Test>::RemovalThresholdTooLarge
+			);
+			assert_ok!(Voting::reopen_proposal(RuntimeOrigin::root(), 1, 20));
+		});
+	}
+}
+
+mod commit_reveal {
+	use super::*;
+	use crate::Commitments;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		Balances::make_free_balance_be(&1, 25u32.into());
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			20,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		let commitment = Voting::commitment_of(&proposal_id, &1, &VoteDecision::Aye(3), &[7; 32]);
+		assert_ok!(Voting::commit_vote(RuntimeOrigin::signed(1), proposal_id, commitment));
+		proposal_id
+	}
+
+	#[test]
+	fn reveals_committed_vote() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::assert_last_event(Event::VoteCommitted { proposal_id, who: 1 }.into());
+
+			//Only the deposit is reserved and the vote stays out of the tallies
+			assert_eq!(Balances::reserved_balance(&1), 1);
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().tally.ayes, 0);
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(1)),
+				Error::<Test>::VoteAlreadyCasted
+			);
+
+			System::set_block_number(20);
+			assert_noop!(
+				Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::ProposalAlreadyEnded
+			);
+			assert_ok!(Voting::reveal_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(3),
+				[7; 32]
+			));
+			System::assert_last_event(
+				Event::VoteCasted { proposal_id, who: 1, vote_decision: VoteDecision::Aye(3) }
+					.into(),
+			);
+
+			assert_eq!(Balances::reserved_balance(&1), 9);
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().tally.ayes, 3);
+			assert!(Voting::vote_casted(&1, &proposal_id));
+			assert!(Commitments::<Test>::get(proposal_id, 1).is_none());
+			assert_ok!(Voting::do_try_state());
+
+			System::set_block_number(21);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().status, ProposalStatus::Passed);
+		});
+	}
+
+	#[test]
+	fn mismatched_reveal_fails() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::set_block_number(20);
+
+			assert_noop!(
+				Voting::reveal_vote(
+					RuntimeOrigin::signed(1),
+					proposal_id,
+					VoteDecision::Aye(4),
+					[7; 32]
+				),
+				Error::<Test>::RevealMismatch
+			);
+			assert_noop!(
+				Voting::reveal_vote(
+					RuntimeOrigin::signed(1),
+					proposal_id,
+					VoteDecision::Aye(3),
+					[8; 32]
+				),
+				Error::<Test>::RevealMismatch
+			);
+		});
+	}
+
+	#[test]
+	fn copied_commitment_fails_to_reveal() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+			Balances::make_free_balance_be(&2, 25u32.into());
+			let (commitment, _) = Commitments::<Test>::get(proposal_id, 1).unwrap();
+			assert_ok!(Voting::commit_vote(RuntimeOrigin::signed(2), proposal_id, commitment));
+			System::set_block_number(20);
+
+			//Knowing the vote and salt of voter 1 doesn't let voter 2 reveal the copy
+			assert_noop!(
+				Voting::reveal_vote(
+					RuntimeOrigin::signed(2),
+					proposal_id,
+					VoteDecision::Aye(3),
+					[7; 32]
+				),
+				Error::<Test>::RevealMismatch
+			);
+		});
+	}
+
+	#[test]
+	fn reveals_only_within_reveal_phase() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_noop!(
+				Voting::reveal_vote(
+					RuntimeOrigin::signed(1),
+					proposal_id,
+					VoteDecision::Aye(3),
+					[7; 32]
+				),
+				Error::<Test>::NotInRevealPhase
+			);
+
+			//The proposal can't be finished while the commitment can still be revealed
+			System::set_block_number(25);
+			assert_noop!(
+				Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id),
+				Error::<Test>::RevealPhaseOngoing
+			);
+
+			System::set_block_number(30);
+			assert_noop!(
+				Voting::reveal_vote(
+					RuntimeOrigin::signed(1),
+					proposal_id,
+					VoteDecision::Aye(3),
+					[7; 32]
+				),
+				Error::<Test>::NotInRevealPhase
+			);
+
+			//Unrevealed commitments return their deposit when the proposal is cleaned up
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::clean_up_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Balances::reserved_balance(&1), 0);
+		});
+	}
+
+	#[test]
+	fn reveal_requires_commitment() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+			System::set_block_number(20);
+
+			assert_noop!(
+				Voting::reveal_vote(
+					RuntimeOrigin::signed(2),
+					proposal_id,
+					VoteDecision::Aye(3),
+					[7; 32]
+				),
+				Error::<Test>::CommitmentNotFound
+			);
+		});
+	}
+}
+
+mod proposer_can_vote {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 2));
+		Balances::make_free_balance_be(&1, 25u32.into());
+		Balances::make_free_balance_be(&2, 25u32.into());
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			20,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		proposal_id
+	}
+
+	#[test]
+	fn proposer_is_blocked_when_disabled() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			ProposerCanVote::set(false);
+
+			assert_noop!(
+				Voting::update_vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)),
+				Error::<Test>::ProposerCannotVote
+			);
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(2),
+				20,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			assert_noop!(
+				Voting::vote(RuntimeOrigin::signed(1), proposal_id + 1, VoteDecision::Aye(2)),
+				Error::<Test>::ProposerCannotVote
+			);
+
+			//Other voters are not affected
+			assert_ok!(Voting::vote(
+				RuntimeOrigin::signed(2),
+				proposal_id + 1,
+				VoteDecision::Nay(2)
+			));
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(2),
+				proposal_id + 1,
+				VoteDecision::Nay(3)
+			));
+		});
+	}
+
+	#[test]
+	fn proposer_can_vote_when_enabled() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(3)
+			));
+			assert_eq!(Voting::get_proposal(&proposal_id).unwrap().tally.ayes, 3);
+		});
+	}
+}
+
+mod membership_deposit {
+	use super::*;
+	use crate::MembershipDeposits;
+
+	#[test]
+	fn reserves_deposit_on_register() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			Balances::make_free_balance_be(&1, 20u32.into());
+
+			assert_ok!(Voting::register_voter_with_deposit(RuntimeOrigin::root(), 1));
+			System::assert_last_event(
+				Event::MembershipDepositReserved { who: 1, deposit: 5 }.into(),
+			);
+			assert!(Voting::is_registered(&1));
+			assert_eq!(MembershipDeposits::<Test>::get(1), Some(5));
+			assert_eq!(Balances::reserved_balance(&1), 5);
+			assert_ok!(Voting::do_try_state());
+		});
+	}
+
+	#[test]
+	fn releases_deposit_on_unregister() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			Balances::make_free_balance_be(&1, 20u32.into());
+			assert_ok!(Voting::register_voter_with_deposit(RuntimeOrigin::root(), 1));
+
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), 1));
+			System::assert_last_event(
+				Event::VoterUnregistered { who: 1, total: 0, deposit_released: 5 }.into(),
+			);
+			assert!(!Voting::is_registered(&1));
+			assert_eq!(MembershipDeposits::<Test>::get(1), None);
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Balances::free_balance(&1), 20);
+		});
+	}
+
+	#[test]
+	fn voter_must_prefund_deposit() {
+		new_test_ext().execute_with(|| {
+			Balances::make_free_balance_be(&1, 3u32.into());
+
+			assert!(Voting::register_voter_with_deposit(RuntimeOrigin::root(), 1).is_err());
+			assert!(!Voting::is_registered(&1));
+		});
+	}
+
+	#[test]
+	fn unregistering_without_deposit_releases_nothing() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), 1));
+			System::assert_last_event(
+				Event::VoterUnregistered { who: 1, total: 0, deposit_released: 0 }.into(),
+			);
+			assert_noop!(
+				Voting::unregister_voter(RuntimeOrigin::root(), 1),
+				Error::<Test>::VoterIsNotRegistered
+			);
+		});
+	}
+}
+
+mod offchain_worker {
+	use super::*;
+
+	#[test]
+	fn lists_expired_proposals_in_progress() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for time_period in [5, 8, 50] {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					time_period,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			System::set_block_number(10);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 1));
+
+			assert_eq!(Voting::proposals_to_finish(), vec![2]);
+		});
+	}
+}
+
+mod tally {
+	use crate::{Tally, VoteDecision};
+	use sp_runtime::ArithmeticError;
+
+	#[test]
+	fn adds_and_subtracts_points() {
+		let mut tally = Tally::default();
+		assert_eq!(tally.add_aye(3), Ok(()));
+		assert_eq!(tally.add_nay(2), Ok(()));
+		assert_eq!(tally.add_abstain(1), Ok(()));
+		assert_eq!(tally.sub_aye(1), Ok(()));
+		assert_eq!(tally, Tally { ayes: 2, nays: 2, abstains: 1 });
+	}
+
+	#[test]
+	fn overflow_and_underflow_are_errors() {
+		let mut tally = Tally { ayes: u32::MAX, nays: 0, abstains: 0 };
+		assert_eq!(tally.add_aye(1), Err(ArithmeticError::Overflow));
+		assert_eq!(tally.sub_nay(1), Err(ArithmeticError::Underflow));
+		assert_eq!(tally, Tally { ayes: u32::MAX, nays: 0, abstains: 0 });
+	}
+
+	#[test]
+	fn scales_vote_decisions_by_multiplier() {
+		let mut tally = Tally::default();
+		assert_eq!(tally.add(&VoteDecision::Split { aye: 2, nay: 1 }, 3), Ok(()));
+		assert_eq!(tally, Tally { ayes: 6, nays: 3, abstains: 0 });
+
+		assert_eq!(tally.sub(&VoteDecision::Aye(2), 3), Ok(()));
+		assert_eq!(tally, Tally { ayes: 0, nays: 3, abstains: 0 });
+		assert!(!tally.is_empty());
+	}
+
+	#[test]
+	fn failed_split_leaves_tally_untouched() {
+		let mut tally = Tally { ayes: 0, nays: u32::MAX, abstains: 0 };
+		assert_eq!(
+			tally.add(&VoteDecision::Split { aye: 1, nay: 1 }, 1),
+			Err(ArithmeticError::Overflow)
+		);
+		assert_eq!(tally.ayes, 0);
+		assert_eq!(tally.add(&VoteDecision::Aye(u32::MAX), 2), Err(ArithmeticError::Overflow));
+	}
+}
+
+mod total_locked {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		for voter in [1, 2] {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			Balances::make_free_balance_be(&voter, 50u32.into());
+		}
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			50,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		proposal_id
+	}
+
+	#[test]
+	fn vote_and_cancel_cycles_return_to_zero() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(2)));
+			assert_eq!(Voting::total_locked(), 13);
+
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(5)
+			));
+			assert_eq!(Voting::total_locked(), 29);
+			assert_ok!(Voting::update_vote(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				VoteDecision::Aye(1)
+			));
+			assert_eq!(Voting::total_locked(), 5);
+			assert_ok!(Voting::do_try_state());
+
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(2), proposal_id));
+			assert_eq!(Voting::total_locked(), 0);
+
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(2)));
+			assert_ok!(Voting::cancel_vote(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Voting::total_locked(), 0);
+			assert_ok!(Voting::do_try_state());
+		});
+	}
+
+	#[test]
+	fn unlocking_returns_to_zero() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(1), proposal_id, VoteDecision::Aye(3)));
+			assert_ok!(Voting::vote(RuntimeOrigin::signed(2), proposal_id, VoteDecision::Nay(2)));
+
+			System::set_block_number(51);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Voting::total_locked(), 4);
+			assert_ok!(Voting::unlock_balance(RuntimeOrigin::signed(2), proposal_id));
+			assert_eq!(Voting::total_locked(), 0);
+			assert_ok!(Voting::do_try_state());
+		});
+	}
+}
+
+mod delegation_cap {
+	use super::*;
+
+	fn before_each() {
+		System::set_block_number(1);
+		for voter in 1..=4 {
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+		}
+	}
+
+	#[test]
+	fn undelegating_frees_a_slot() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			MaxDelegationsPerAccount::set(2);
+
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 1));
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 1));
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(4), 1),
+				Error::<Test>::DelegationCapReached
+			);
+			//Delegating again to the same account doesn't need a new slot
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 1));
+			assert_eq!(Voting::voting_multiplier(&1), 3);
+
+			assert_ok!(Voting::undelegate(RuntimeOrigin::signed(2)));
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(4), 1));
+			assert_eq!(Voting::voting_multiplier(&1), 3);
+		});
+	}
+
+	#[test]
+	fn admin_can_adjust_cap_of_an_account() {
+		new_test_ext().execute_with(|| {
+			before_each();
+			assert_noop!(
+				Voting::set_delegation_cap(RuntimeOrigin::signed(1), 1, Some(1)),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			assert_ok!(Voting::set_delegation_cap(RuntimeOrigin::root(), 1, Some(1)));
+			System::assert_last_event(Event::DelegationCapSet { who: 1, cap: 1 }.into());
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 1));
+			assert_noop!(
+				Voting::delegate(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::DelegationCapReached
+			);
+
+			//Going back to the configured cap
+			assert_ok!(Voting::set_delegation_cap(RuntimeOrigin::root(), 1, None));
+			assert_eq!(Voting::delegation_cap(&1), 10);
+			assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 1));
+		});
+	}
+}
+
+mod time_remaining {
+	use super::*;
+
+	fn before_each() -> u32 {
+		System::set_block_number(1);
+		let proposal_id = Voting::get_proposal_counter() + 1;
+		assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+		assert_ok!(Voting::make_proposal(
+			RuntimeOrigin::signed(1),
+			sp_core::H256::repeat_byte(1),
+			20,
+			None,
+			None,
+			None,
+			vec![],
+			None
+		));
+		proposal_id
+	}
+
+	#[test]
+	fn before_deadline() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			assert_eq!(Voting::time_remaining(&proposal_id), Some(19));
+
+			System::set_block_number(15);
+			assert_eq!(Voting::time_remaining(&proposal_id), Some(5));
+		});
+	}
+
+	#[test]
+	fn at_deadline() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::set_block_number(20);
+			assert_eq!(Voting::time_remaining(&proposal_id), Some(0));
+		});
+	}
+
+	#[test]
+	fn past_deadline() {
+		new_test_ext().execute_with(|| {
+			let proposal_id = before_each();
+			System::set_block_number(25);
+			assert_eq!(Voting::time_remaining(&proposal_id), Some(0));
+
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), proposal_id));
+			assert_eq!(Voting::time_remaining(&proposal_id), Some(0));
+			assert_eq!(Voting::time_remaining(&(proposal_id + 1)), None);
+		});
+	}
+}
+
+mod voter_capacity_warning {
+	use super::*;
+
+	fn warnings() -> usize {
+		System::events()
+			.iter()
+			.filter(|record| {
+				matches!(record.event, RuntimeEvent::Voting(Event::VoterCapacityWarning { .. }))
+			})
+			.count()
+	}
+
+	#[test]
+	fn fires_once_per_crossing() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			MaxVoters::set(5);
+			CapacityWarnThreshold::set(2);
+
+			for voter in 1..=3 {
+				assert_ok!(Voting::register_voter(RuntimeOrigin::root(), voter));
+			}
+			assert_eq!(warnings(), 0);
+
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 4));
+			System::assert_last_event(Event::VoterCapacityWarning { remaining: 1 }.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 5));
+			assert_eq!(warnings(), 1);
+
+			//Still below the threshold, the warning is not rearmed
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), 5));
+			assert!(crate::CapacityWarningIssued::<Test>::get());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 5));
+			assert_eq!(warnings(), 1);
+
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), 5));
+			assert_ok!(Voting::unregister_voter(RuntimeOrigin::root(), 4));
+			assert!(!crate::CapacityWarningIssued::<Test>::get());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 4));
+			assert_eq!(warnings(), 2);
+		});
+	}
+
+	#[test]
+	fn batch_registration_fires_once() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			MaxVoters::set(5);
+			CapacityWarnThreshold::set(3);
+
+			let voters: Vec<u64> = (1..=5).collect();
+			assert_ok!(Voting::batch_register_voters(
+				RuntimeOrigin::root(),
+				voters.try_into().unwrap()
+			));
+			System::assert_has_event(Event::VoterCapacityWarning { remaining: 0 }.into());
+			assert_eq!(warnings(), 1);
+		});
+	}
+}
+
+mod active_proposal_ids {
+	use super::*;
+	use crate::ActiveProposalIds;
+
+	#[test]
+	fn follows_the_proposals_in_progress() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			for _ in 0..3 {
+				assert_ok!(Voting::make_proposal(
+					RuntimeOrigin::signed(1),
+					sp_core::H256::repeat_byte(1),
+					10,
+					None,
+					None,
+					None,
+					vec![],
+					None
+				));
+			}
+			assert_eq!(
+				ActiveProposalIds::<Test>::get().into_inner(),
+				vec![(10, 1), (10, 2), (10, 3)]
+			);
+
+			assert_ok!(Voting::cancel_proposal(RuntimeOrigin::signed(1), 2));
+			assert_eq!(ActiveProposalIds::<Test>::get().into_inner(), vec![(10, 1), (10, 3)]);
+
+			//Extending a proposal moves it after the ones ending earlier
+			assert_ok!(Voting::increase_proposal_time(RuntimeOrigin::signed(1), 1, 30));
+			assert_eq!(ActiveProposalIds::<Test>::get().into_inner(), vec![(10, 3), (30, 1)]);
+
+			System::set_block_number(11);
+			assert_ok!(Voting::finish_proposal(RuntimeOrigin::signed(1), 3));
+			assert_eq!(ActiveProposalIds::<Test>::get().into_inner(), vec![(30, 1)]);
+
+			assert_ok!(Voting::reopen_proposal(RuntimeOrigin::root(), 2, 20));
+			assert_eq!(ActiveProposalIds::<Test>::get().into_inner(), vec![(20, 2), (30, 1)]);
+			assert_ok!(Voting::do_try_state());
+		});
+	}
+}
+
+mod proposal_count_weights {
+	use super::*;
+	use crate::{Call, Proposals, WeightInfo};
+	use frame_support::{dispatch::GetDispatchInfo, traits::Hooks, weights::Weight};
+
+	fn weights() -> Vec<Weight> {
+		let calls: Vec<Call<Test>> = vec![
+			Call::make_proposal {
+				description: sp_core::H256::repeat_byte(1),
+				time_period: 100,
+				min_turnout: None,
+				per_voter_limit: None,
+				call: None,
+				tags: vec![],
+				removal_threshold: None,
+			},
+			Call::increase_proposal_time { proposal_id: 1, new_time_period: 100 },
+			Call::cancel_proposal { proposal_id: 1 },
+			Call::update_vote { proposal_id: 1, new_vote_decision: VoteDecision::Aye(2) },
+			Call::cancel_vote { proposal_id: 1 },
+			Call::finish_proposal { proposal_id: 1 },
+			Call::force_finish_proposal { proposal_id: 1 },
+			Call::reopen_proposal { proposal_id: 1, new_time_period: 100 },
+			Call::unlock_balance { proposal_id: 1 },
+		];
+		calls.iter().map(|call| call.get_dispatch_info().weight).collect()
+	}
+
+	#[test]
+	fn weights_do_not_grow_with_stored_proposals() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			SnapshotInterval::set(5);
+			Balances::make_free_balance_be(&1, 25u32.into());
+			assert_ok!(Voting::register_voter(RuntimeOrigin::root(), 1));
+			assert_ok!(Voting::make_proposal(
+				RuntimeOrigin::signed(1),
+				sp_core::H256::repeat_byte(1),
+				50,
+				None,
+				None,
+				None,
+				vec![],
+				None
+			));
+			let before = weights();
+			let snapshots_before = Voting::on_initialize(5);
+
+			//Ended proposals pile up in storage until they are cleaned up
+			for id in 2..=200 {
+				let mut proposal =
+					Proposal::<Test>::new(id, 1, sp_core::H256::repeat_byte(1), 10, 0, None, None);
+				proposal.status = ProposalStatus::Passed;
+				Proposals::<Test>::insert(id, proposal);
+			}
+			crate::ProposalCounter::<Test>::put(200);
+
+			assert_eq!(weights(), before);
+			//Votes are weighed with the depth of the proposals map
+			let vote = Call::<Test>::vote { proposal_id: 1, vote_decision: VoteDecision::Aye(1) };
+			assert_eq!(vote.get_dispatch_info().weight, <() as WeightInfo>::vote(200));
+			assert_eq!(Voting::on_initialize(10), snapshots_before);
+			assert_eq!(crate::TallySnapshots::<Test>::iter().count(), 2);
+
+			//on_idle only walks the proposals in progress, finishing the expired one
+			System::set_block_number(51);
+			assert_eq!(Voting::on_idle(51, Weight::MAX), <() as WeightInfo>::finish_proposal());
+			assert_eq!(Voting::get_proposal(&1).unwrap().status, ProposalStatus::Expired);
+		});
+	}
+}
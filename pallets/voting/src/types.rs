@@ -1,8 +1,9 @@
 use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{sp_runtime::ArithmeticError, BoundedVec};
 use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 
-use crate::{Config, ProposalId};
+use crate::{BalanceOf, Config, ProposalId, TagOf};
 
 #[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone)]
 #[scale_info(skip_type_params(T))]
@@ -12,8 +13,29 @@ pub struct Proposal<T: Config> {
 	pub text: T::Hash,
 	pub time_period: BlockNumberFor<T>,
 	pub status: ProposalStatus,
-	pub ayes: u32,
-	pub nays: u32,
+	/// Points casted in favor of, against and abstaining on the proposal.
+	pub tally: Tally,
+	/// Balance reserved from the proposer when the proposal was made.
+	pub deposit: BalanceOf<T>,
+	/// Block in which the proposal was made.
+	pub created_at: BlockNumberFor<T>,
+	/// Quadratic voting power in favor of the proposal. See `Pallet::quadratic_power`.
+	pub quadratic_ayes: u32,
+	/// Quadratic voting power against the proposal. See `Pallet::quadratic_power`.
+	pub quadratic_nays: u32,
+	/// Turnout required by the proposer on top of the global quorum.
+	pub min_turnout: Option<u32>,
+	/// Limit of points per voter set by the proposer on top of the global vote limit.
+	pub per_voter_limit: Option<u32>,
+	/// Hash of the encoded call dispatched if the proposal passes.
+	pub call_hash: Option<T::Hash>,
+	/// Tags categorizing the proposal.
+	pub tags: BoundedVec<TagOf<T>, T::MaxTags>,
+	/// Block in which the proposal stopped being in progress.
+	pub ended_at: Option<BlockNumberFor<T>>,
+	/// Blocks before the end of the proposal in which votes can't be reduced or canceled,
+	/// replacing the global `VoteRemovalThreshold`.
+	pub removal_threshold: Option<u32>,
 }
 
 impl<T: Config> Proposal<T> {
@@ -22,6 +44,9 @@ impl<T: Config> Proposal<T> {
 		proposer: T::AccountId,
 		text: T::Hash,
 		time_period: BlockNumberFor<T>,
+		deposit: BalanceOf<T>,
+		min_turnout: Option<u32>,
+		per_voter_limit: Option<u32>,
 	) -> Self {
 		Proposal {
 			id,
@@ -29,30 +54,278 @@ impl<T: Config> Proposal<T> {
 			text,
 			time_period,
 			status: ProposalStatus::InProgress,
-			ayes: 0,
-			nays: 0,
+			tally: Tally::default(),
+			deposit,
+			created_at: frame_system::Pallet::<T>::block_number(),
+			quadratic_ayes: 0,
+			quadratic_nays: 0,
+			min_turnout,
+			per_voter_limit,
+			call_hash: None,
+			tags: Default::default(),
+			ended_at: None,
+			removal_threshold: None,
+		}
+	}
+}
+
+/// Points casted on a proposal, split by direction. Every operation is checked, so the tallies
+/// can never wrap around.
+///
+/// Encodes like the `ayes`, `nays` and `abstains` fields it replaced in `Proposal`.
+#[derive(Encode, Debug, Decode, Clone, Copy, TypeInfo, MaxEncodedLen, Eq, PartialEq, Default)]
+pub struct Tally {
+	pub ayes: u32,
+	pub nays: u32,
+	pub abstains: u32,
+}
+
+impl Tally {
+	pub fn add_aye(&mut self, points: u32) -> Result<(), ArithmeticError> {
+		self.ayes = self.ayes.checked_add(points).ok_or(ArithmeticError::Overflow)?;
+		Ok(())
+	}
+
+	pub fn add_nay(&mut self, points: u32) -> Result<(), ArithmeticError> {
+		self.nays = self.nays.checked_add(points).ok_or(ArithmeticError::Overflow)?;
+		Ok(())
+	}
+
+	pub fn add_abstain(&mut self, points: u32) -> Result<(), ArithmeticError> {
+		self.abstains = self.abstains.checked_add(points).ok_or(ArithmeticError::Overflow)?;
+		Ok(())
+	}
+
+	pub fn sub_aye(&mut self, points: u32) -> Result<(), ArithmeticError> {
+		self.ayes = self.ayes.checked_sub(points).ok_or(ArithmeticError::Underflow)?;
+		Ok(())
+	}
+
+	pub fn sub_nay(&mut self, points: u32) -> Result<(), ArithmeticError> {
+		self.nays = self.nays.checked_sub(points).ok_or(ArithmeticError::Underflow)?;
+		Ok(())
+	}
+
+	pub fn sub_abstain(&mut self, points: u32) -> Result<(), ArithmeticError> {
+		self.abstains = self.abstains.checked_sub(points).ok_or(ArithmeticError::Underflow)?;
+		Ok(())
+	}
+
+	/// Adds the points of `vote_decision`, scaled by `multiplier`. The tally is left untouched
+	/// if any side overflows.
+	pub fn add(
+		&mut self,
+		vote_decision: &VoteDecision,
+		multiplier: u32,
+	) -> Result<(), ArithmeticError> {
+		let mut tally = *self;
+		match *vote_decision {
+			VoteDecision::Aye(v) => tally.add_aye(scale(v, multiplier)?)?,
+			VoteDecision::Nay(v) => tally.add_nay(scale(v, multiplier)?)?,
+			VoteDecision::Abstain(v) => tally.add_abstain(scale(v, multiplier)?)?,
+			VoteDecision::Split { aye, nay } => {
+				tally.add_aye(scale(aye, multiplier)?)?;
+				tally.add_nay(scale(nay, multiplier)?)?;
+			},
 		}
+		*self = tally;
+		Ok(())
 	}
+
+	/// Removes the points of `vote_decision`, scaled by `multiplier`. The tally is left
+	/// untouched if any side underflows.
+	pub fn sub(
+		&mut self,
+		vote_decision: &VoteDecision,
+		multiplier: u32,
+	) -> Result<(), ArithmeticError> {
+		let mut tally = *self;
+		match *vote_decision {
+			VoteDecision::Aye(v) => tally.sub_aye(scale(v, multiplier)?)?,
+			VoteDecision::Nay(v) => tally.sub_nay(scale(v, multiplier)?)?,
+			VoteDecision::Abstain(v) => tally.sub_abstain(scale(v, multiplier)?)?,
+			VoteDecision::Split { aye, nay } => {
+				tally.sub_aye(scale(aye, multiplier)?)?;
+				tally.sub_nay(scale(nay, multiplier)?)?;
+			},
+		}
+		*self = tally;
+		Ok(())
+	}
+
+	/// Whether no points at all were casted.
+	pub fn is_empty(&self) -> bool {
+		self.ayes == 0 && self.nays == 0 && self.abstains == 0
+	}
+}
+
+fn scale(points: u32, multiplier: u32) -> Result<u32, ArithmeticError> {
+	points.checked_mul(multiplier).ok_or(ArithmeticError::Overflow)
 }
 
 #[derive(Encode, Debug, Decode, Clone, TypeInfo, MaxEncodedLen, Eq, PartialEq)]
-pub struct Vote {
+pub struct Vote<Balance> {
 	pub vote_decision: VoteDecision,
 	pub locked: bool,
+	/// Factor the vote points were multiplied by when added to the proposal tallies.
+	pub multiplier: u32,
+	/// Conviction the vote was casted with, locking its balance past the end of the proposal.
+	pub conviction: Conviction,
+	/// Quadratic voting power the vote added in favor of the proposal. See
+	/// `Pallet::quadratic_power`.
+	pub quadratic_ayes: u32,
+	/// Quadratic voting power the vote added against the proposal. See `Pallet::quadratic_power`.
+	pub quadratic_nays: u32,
+	/// Balance reserved from the voter for the vote, returned as is when the vote is canceled
+	/// or unlocked.
+	pub reserved: Balance,
+}
+
+/// How long a voter commits to keep their balance locked after the proposal ends, in exchange
+/// for multiplying their voting power. Modeled after the convictions of `pallet-democracy`.
+#[derive(Encode, Debug, Decode, Clone, Copy, TypeInfo, MaxEncodedLen, Eq, PartialEq)]
+pub enum Conviction {
+	/// No lock past the end of the proposal and no extra voting power.
+	None,
+	Locked1x,
+	Locked2x,
+	Locked3x,
+	Locked4x,
+	Locked5x,
+	Locked6x,
+}
+
+impl Conviction {
+	/// Factor the vote points are multiplied by.
+	pub fn multiplier(self) -> u32 {
+		match self {
+			Conviction::None | Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 3,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 5,
+			Conviction::Locked6x => 6,
+		}
+	}
+
+	/// Number of `ConvictionPeriod`s the balance stays locked after the proposal ends.
+	pub fn lock_periods(self) -> u32 {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked1x => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 4,
+			Conviction::Locked4x => 8,
+			Conviction::Locked5x => 16,
+			Conviction::Locked6x => 32,
+		}
+	}
 }
 
 #[derive(Encode, Debug, Decode, Clone, TypeInfo, MaxEncodedLen, Eq, PartialEq)]
 pub enum VoteDecision {
 	Aye(u32),
 	Nay(u32),
+	Abstain(u32),
+	/// Hedged vote putting `aye` points in favor and `nay` points against the proposal. Both
+	/// sides count as a single vote of `aye + nay` points: their sum is bounded by the vote
+	/// limit and the reserved balance is `(aye + nay)^2`.
+	Split {
+		aye: u32,
+		nay: u32,
+	},
+}
+
+impl VoteDecision {
+	/// Number of points of the vote, whatever its direction. Split votes add up both sides.
+	pub fn amount(&self) -> u32 {
+		match self {
+			VoteDecision::Aye(v) | VoteDecision::Nay(v) | VoteDecision::Abstain(v) => *v,
+			VoteDecision::Split { aye, nay } => aye.saturating_add(*nay),
+		}
+	}
+
+	/// Whether the vote is in favor of the proposal.
+	pub fn is_aye(&self) -> bool {
+		matches!(self, VoteDecision::Aye(_))
+	}
+
+	/// Direction of the vote, without its points. Split votes have no single direction.
+	pub fn direction(&self) -> Option<VoteDirection> {
+		match self {
+			VoteDecision::Aye(_) => Some(VoteDirection::Aye),
+			VoteDecision::Nay(_) => Some(VoteDirection::Nay),
+			VoteDecision::Abstain(_) => Some(VoteDirection::Abstain),
+			VoteDecision::Split { .. } => None,
+		}
+	}
+}
+
+/// Side of a vote, without the number of points.
+#[derive(Encode, Debug, Decode, Clone, TypeInfo, MaxEncodedLen, Eq, PartialEq)]
+pub enum VoteDirection {
+	Aye,
+	Nay,
+	Abstain,
+}
+
+impl VoteDirection {
+	/// Builds the vote decision casting `points` in this direction.
+	pub fn with_points(self, points: u32) -> VoteDecision {
+		match self {
+			VoteDirection::Aye => VoteDecision::Aye(points),
+			VoteDirection::Nay => VoteDecision::Nay(points),
+			VoteDirection::Abstain => VoteDecision::Abstain(points),
+		}
+	}
 }
 
-#[derive(Encode, Debug, Decode, TypeInfo, MaxEncodedLen, Clone, Eq, PartialEq)]
+#[derive(Encode, Debug, Decode, TypeInfo, MaxEncodedLen, Clone, Copy, Eq, PartialEq, Default)]
 #[scale_info(skip_type_params(T))]
 pub enum ProposalStatus {
+	#[default]
 	InProgress,
 	Canceled,
 	Passed,
 	Rejected,
 	Tied,
+	NoQuorum,
+	Expired,
+}
+
+/// Live standing of a proposal, as exposed through the runtime API.
+#[derive(Encode, Debug, Decode, Clone, TypeInfo, Eq, PartialEq)]
+pub struct ProposalStateInfo<BlockNumber> {
+	pub ayes: u32,
+	pub nays: u32,
+	pub abstains: u32,
+	pub turnout: u32,
+	pub time_period: BlockNumber,
+	/// Status the proposal would get if it was finished now. For proposals that already ended
+	/// this is their final status.
+	pub projected_status: ProposalStatus,
+}
+
+/// Decides how proposals that end with as many ayes as nays are resolved.
+pub trait TieBreakStrategy {
+	/// Final status of a tied proposal, one of `Passed`, `Rejected` or `Tied`.
+	fn resolve_tie() -> ProposalStatus;
+}
+
+/// Rejects tied proposals, keeping the status quo.
+pub struct AlwaysReject;
+
+impl TieBreakStrategy for AlwaysReject {
+	fn resolve_tie() -> ProposalStatus {
+		ProposalStatus::Rejected
+	}
+}
+
+/// Leaves tied proposals with the `Tied` status.
+pub struct AlwaysTie;
+
+impl TieBreakStrategy for AlwaysTie {
+	fn resolve_tie() -> ProposalStatus {
+		ProposalStatus::Tied
+	}
 }
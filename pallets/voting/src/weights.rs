@@ -1,32 +1,11 @@
-
-//! Autogenerated weights for pallet_voting
+//! Weights for pallet_voting.
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-11-17, STEPS: `10`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `pop-os`, CPU: `11th Gen Intel(R) Core(TM) i7-1165G7 @ 2.80GHz`
-//! EXECUTION: , WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 1024
-
-// Executed Command:
-// ./target/release/node-template
-// benchmark
-// pallet
-// --steps
-// 10
-// --template
-// ./.mantain/template.hbs
-// --repeat
-// 20
-// --extrinsic
-// *
-// --wasm-execution
-// compiled
-// --heap-pages
-// 4096
-// --pallet
-// pallet_voting
-// --output
-// ./pallets/voting/src/weights.rs
+//! The weights are estimated by hand from the storage each call reads and writes, and are
+//! placeholders until they are regenerated with the `benchmark pallet` command of the node:
+//!
+//! ./target/release/node-template benchmark pallet --pallet pallet_voting --extrinsic '*'
+//!   --steps 10 --repeat 20 --wasm-execution compiled --heap-pages 4096
+//!   --template ./.mantain/template.hbs --output ./pallets/voting/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -40,69 +19,388 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn register_voter() -> Weight;
 	fn make_proposal() -> Weight;
-	fn increase_proposal_time(x: u32, ) -> Weight;
-	fn cancel_proposal(x: u32, ) -> Weight;
+	fn increase_proposal_time() -> Weight;
+	fn cancel_proposal(v: u32, ) -> Weight;
+	fn register_voters(n: u32, ) -> Weight;
+	fn delegate(p: u32, ) -> Weight;
+	fn undelegate(p: u32, ) -> Weight;
+	fn unlock_balance_batch(n: u32, ) -> Weight;
+	fn force_finish_proposal() -> Weight;
+	fn set_parameters() -> Weight;
+	fn update_vote_increase() -> Weight;
+	fn update_vote_decrease() -> Weight;
+	fn cancel_vote() -> Weight;
+	fn finish_proposal() -> Weight;
+	fn unlock_balance() -> Weight;
+	fn vote(x: u32, ) -> Weight;
+	fn switch_vote() -> Weight;
+	fn clean_up_proposal(v: u32, ) -> Weight;
+	fn snapshot_tallies(p: u32, ) -> Weight;
+	fn force_register_voter() -> Weight;
+	fn set_paused() -> Weight;
+	fn transfer_proposal_ownership() -> Weight;
+	fn bulk_finish_proposals(n: u32, ) -> Weight;
+	fn reopen_proposal() -> Weight;
+	fn commit_vote() -> Weight;
+	fn reveal_vote() -> Weight;
+	fn register_voter_with_deposit() -> Weight;
+	fn unregister_voter(p: u32, ) -> Weight;
+	fn set_delegation_cap() -> Weight;
 }
 
-/// Weights for pallet_voting using the Substrate node and recommended hardware.
+/// Weights for pallet_voting using the Substrate node database weights.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
-	/// Proof: `Voting::RegisteredVoters` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Voting::AmountVoters` (r:1 w:1)
-	/// Proof: `Voting::AmountVoters` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
 	fn register_voter() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `6`
-		//  Estimated: `3513`
-		// Minimum execution time: 22_813_000 picoseconds.
 		Weight::from_parts(23_276_000, 3513)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
-	/// Proof: `Voting::RegisteredVoters` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Voting::LastProposalBlock` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalCount` (r:1 w:1)
 	/// Storage: `Voting::ProposalCounter` (r:1 w:1)
-	/// Proof: `Voting::ProposalCounter` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::StatusCounts` (r:1 w:1)
 	/// Storage: `Voting::Proposals` (r:0 w:1)
-	/// Proof: `Voting::Proposals` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Voting::ExpiringAt` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
 	fn make_proposal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `103`
-		//  Estimated: `3513`
-		// Minimum execution time: 26_987_000 picoseconds.
-		Weight::from_parts(27_949_000, 3513)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		Weight::from_parts(48_622_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::ExpiringAt` (r:2 w:2)
+	fn increase_proposal_time() -> Weight {
+		Weight::from_parts(31_097_000, 3566)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::ActiveProposalCount` (r:1 w:1)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:99 w:99)
+	/// Storage: `System::Account` (r:99 w:99)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:99 w:99)
+	/// Storage: `Voting::ExpiringAt` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	/// Storage: `Voting::CanceledVotes` (r:0 w:99)
+	/// The range of component `v` is `[0, 99]`.
+	fn cancel_proposal(v: u32, ) -> Weight {
+		Weight::from_parts(33_870_145, 6687)
+			.saturating_add(Weight::from_parts(19_412_830, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(v.into()))
+	}
+	/// Storage: `Voting::AmountVoters` (r:1 w:1)
+	/// Storage: `Voting::RegisteredVoters` (r:50 w:50)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn register_voters(n: u32, ) -> Weight {
+		Weight::from_parts(15_382_710, 1489)
+			.saturating_add(Weight::from_parts(11_205_416, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:2 w:0)
+	/// Storage: `Voting::Delegations` (r:2 w:1)
+	/// Storage: `Voting::IncomingDelegations` (r:3 w:2)
+	/// Storage: `Voting::ActiveProposalIds` (r:2 w:0)
+	/// Storage: `Voting::Votes` (r:200 w:0)
+	/// Storage: `Voting::Commitments` (r:200 w:0)
+	/// Storage: `Voting::DelegationCapOverride` (r:1 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn delegate(p: u32, ) -> Weight {
+		Weight::from_parts(39_562_000, 6036)
+			.saturating_add(Weight::from_parts(9_204_318, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 5190).saturating_mul(p.into()))
+	}
+	/// Storage: `Voting::Delegations` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:0)
+	/// Storage: `Voting::Votes` (r:100 w:0)
+	/// Storage: `Voting::Commitments` (r:100 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:1)
+	/// The range of component `p` is `[0, 100]`.
+	fn undelegate(p: u32, ) -> Weight {
+		Weight::from_parts(24_180_000, 3545)
+			.saturating_add(Weight::from_parts(4_611_904, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(p.into()))
+	}
+	/// Storage: `Voting::Proposals` (r:50 w:0)
+	/// Storage: `Voting::Votes` (r:50 w:50)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:50 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn unlock_balance_batch(n: u32, ) -> Weight {
+		Weight::from_parts(19_874_311, 3593)
+			.saturating_add(Weight::from_parts(14_620_583, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:0)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::ExpiringAt` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	fn force_finish_proposal() -> Weight {
+		Weight::from_parts(18_604_000, 3566)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Voting::AmountVoters` (r:1 w:0)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:1)
+	/// Storage: `Voting::VoteRemovalThresholdOverride` (r:1 w:1)
+	fn set_parameters() -> Weight {
+		Weight::from_parts(12_887_000, 1489)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn update_vote_increase() -> Weight {
+		Weight::from_parts(43_125_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
-	/// Proof: `Voting::RegisteredVoters` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Voting::Proposals` (r:1 w:1)
-	/// Proof: `Voting::Proposals` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::VoteRemovalThresholdOverride` (r:1 w:0)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn update_vote_decrease() -> Weight {
+		Weight::from_parts(41_762_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::VoteRemovalThresholdOverride` (r:1 w:0)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:1 w:1)
+	fn cancel_vote() -> Weight {
+		Weight::from_parts(45_790_000, 6687)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:0)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	fn finish_proposal() -> Weight {
+		Weight::from_parts(23_318_000, 3566)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:0)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:1 w:1)
+	fn unlock_balance() -> Weight {
+		Weight::from_parts(31_002_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Delegations` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:1 w:1)
 	/// The range of component `x` is `[1, 10000]`.
-	fn increase_proposal_time(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1303`
-		//  Estimated: `3566`
-		// Minimum execution time: 30_568_000 picoseconds.
-		Weight::from_parts(55_483_886, 3566)
-			// Standard Error: 332
-			.saturating_add(Weight::from_parts(2_744, 0).saturating_mul(x.into()))
+	fn vote(x: u32, ) -> Weight {
+		Weight::from_parts(61_204_511, 6687)
+			.saturating_add(Weight::from_parts(2_731, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Voting::Votes` (r:2 w:1)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	fn switch_vote() -> Weight {
+		Weight::from_parts(34_612_000, 3566)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:100 w:100)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// The range of component `v` is `[1, 100]`.
+	fn clean_up_proposal(v: u32, ) -> Weight {
+		Weight::from_parts(21_433_920, 6687)
+			.saturating_add(Weight::from_parts(6_904_201, 0).saturating_mul(v.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(v.into()))
+	}
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:0)
+	/// Storage: `Voting::NextSnapshotCursor` (r:1 w:1)
+	/// Storage: `Voting::Proposals` (r:10 w:0)
+	/// Storage: `Voting::TallySnapshots` (r:0 w:10)
+	/// The range of component `p` is `[0, 10]`.
+	fn snapshot_tallies(p: u32, ) -> Weight {
+		Weight::from_parts(3_498_215, 1493)
+			.saturating_add(Weight::from_parts(5_123_770, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2585).saturating_mul(p.into()))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::AmountVoters` (r:1 w:1)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
+	fn force_register_voter() -> Weight {
+		Weight::from_parts(22_640_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Paused` (r:0 w:1)
+	fn set_paused() -> Weight {
+		Weight::from_parts(7_608_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::ProposalsByProposer` (r:2 w:2)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn transfer_proposal_ownership() -> Weight {
+		Weight::from_parts(49_702_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:50 w:50)
+	/// Storage: `Voting::VoteCount` (r:50 w:0)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::RegisteredVoters` (r:50 w:0)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn bulk_finish_proposals(n: u32, ) -> Weight {
+		Weight::from_parts(12_846_097, 3566)
+			.saturating_add(Weight::from_parts(11_927_314, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
 	/// Storage: `Voting::Proposals` (r:1 w:1)
-	/// Proof: `Voting::Proposals` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 10000]`.
-	fn cancel_proposal(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1266`
-		//  Estimated: `3566`
-		// Minimum execution time: 25_731_000 picoseconds.
-		Weight::from_parts(48_152_940, 3566)
-			// Standard Error: 287
-			.saturating_add(Weight::from_parts(2_652, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+	/// Storage: `Voting::ActiveProposalCount` (r:1 w:1)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::ExpiringAt` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	fn reopen_proposal() -> Weight {
+		Weight::from_parts(20_512_000, 3566)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Voting::Paused` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Delegations` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:0)
+	/// Storage: `Voting::Votes` (r:1 w:0)
+	/// Storage: `Voting::Commitments` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn commit_vote() -> Weight {
+		Weight::from_parts(28_104_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Paused` (r:1 w:0)
+	/// Storage: `Voting::Commitments` (r:1 w:1)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::CanceledVotes` (r:0 w:1)
+	fn reveal_vote() -> Weight {
+		Weight::from_parts(54_236_000, 6687)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::AmountVoters` (r:1 w:1)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::MembershipDeposits` (r:0 w:1)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
+	fn register_voter_with_deposit() -> Weight {
+		Weight::from_parts(37_502_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::AmountVoters` (r:1 w:1)
+	/// Storage: `Voting::MembershipDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
+	/// Storage: `Voting::IncomingDelegations` (r:2 w:1)
+	/// Storage: `Voting::Delegations` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:0)
+	/// Storage: `Voting::Votes` (r:100 w:0)
+	/// Storage: `Voting::Commitments` (r:100 w:0)
+	/// Storage: `Voting::DelegationCapOverride` (r:0 w:1)
+	/// The range of component `p` is `[0, 100]`.
+	fn unregister_voter(p: u32, ) -> Weight {
+		Weight::from_parts(43_506_000, 3593)
+			.saturating_add(Weight::from_parts(4_611_904, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(p.into()))
+	}
+	/// Storage: `Voting::DelegationCapOverride` (r:0 w:1)
+	fn set_delegation_cap() -> Weight {
+		Weight::from_parts(10_245_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
@@ -110,61 +408,355 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 // For backwards compatibility and tests
 impl WeightInfo for () {
 	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
-	/// Proof: `Voting::RegisteredVoters` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Voting::AmountVoters` (r:1 w:1)
-	/// Proof: `Voting::AmountVoters` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
 	fn register_voter() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `6`
-		//  Estimated: `3513`
-		// Minimum execution time: 22_813_000 picoseconds.
 		Weight::from_parts(23_276_000, 3513)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
-	/// Proof: `Voting::RegisteredVoters` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Voting::LastProposalBlock` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalCount` (r:1 w:1)
 	/// Storage: `Voting::ProposalCounter` (r:1 w:1)
-	/// Proof: `Voting::ProposalCounter` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::StatusCounts` (r:1 w:1)
 	/// Storage: `Voting::Proposals` (r:0 w:1)
-	/// Proof: `Voting::Proposals` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Voting::ExpiringAt` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
 	fn make_proposal() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `103`
-		//  Estimated: `3513`
-		// Minimum execution time: 26_987_000 picoseconds.
-		Weight::from_parts(27_949_000, 3513)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		Weight::from_parts(48_622_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::ExpiringAt` (r:2 w:2)
+	fn increase_proposal_time() -> Weight {
+		Weight::from_parts(31_097_000, 3566)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::ActiveProposalCount` (r:1 w:1)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:99 w:99)
+	/// Storage: `System::Account` (r:99 w:99)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:99 w:99)
+	/// Storage: `Voting::ExpiringAt` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	/// Storage: `Voting::CanceledVotes` (r:0 w:99)
+	/// The range of component `v` is `[0, 99]`.
+	fn cancel_proposal(v: u32, ) -> Weight {
+		Weight::from_parts(33_870_145, 6687)
+			.saturating_add(Weight::from_parts(19_412_830, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(v.into()))
+	}
+	/// Storage: `Voting::AmountVoters` (r:1 w:1)
+	/// Storage: `Voting::RegisteredVoters` (r:50 w:50)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn register_voters(n: u32, ) -> Weight {
+		Weight::from_parts(15_382_710, 1489)
+			.saturating_add(Weight::from_parts(11_205_416, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:2 w:0)
+	/// Storage: `Voting::Delegations` (r:2 w:1)
+	/// Storage: `Voting::IncomingDelegations` (r:3 w:2)
+	/// Storage: `Voting::ActiveProposalIds` (r:2 w:0)
+	/// Storage: `Voting::Votes` (r:200 w:0)
+	/// Storage: `Voting::Commitments` (r:200 w:0)
+	/// Storage: `Voting::DelegationCapOverride` (r:1 w:0)
+	/// The range of component `p` is `[0, 100]`.
+	fn delegate(p: u32, ) -> Weight {
+		Weight::from_parts(39_562_000, 6036)
+			.saturating_add(Weight::from_parts(9_204_318, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 5190).saturating_mul(p.into()))
+	}
+	/// Storage: `Voting::Delegations` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:0)
+	/// Storage: `Voting::Votes` (r:100 w:0)
+	/// Storage: `Voting::Commitments` (r:100 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:1)
+	/// The range of component `p` is `[0, 100]`.
+	fn undelegate(p: u32, ) -> Weight {
+		Weight::from_parts(24_180_000, 3545)
+			.saturating_add(Weight::from_parts(4_611_904, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(p.into()))
+	}
+	/// Storage: `Voting::Proposals` (r:50 w:0)
+	/// Storage: `Voting::Votes` (r:50 w:50)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:50 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn unlock_balance_batch(n: u32, ) -> Weight {
+		Weight::from_parts(19_874_311, 3593)
+			.saturating_add(Weight::from_parts(14_620_583, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:0)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::ExpiringAt` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	fn force_finish_proposal() -> Weight {
+		Weight::from_parts(18_604_000, 3566)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Voting::AmountVoters` (r:1 w:0)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:1)
+	/// Storage: `Voting::VoteRemovalThresholdOverride` (r:1 w:1)
+	fn set_parameters() -> Weight {
+		Weight::from_parts(12_887_000, 1489)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn update_vote_increase() -> Weight {
+		Weight::from_parts(43_125_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::VoteRemovalThresholdOverride` (r:1 w:0)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn update_vote_decrease() -> Weight {
+		Weight::from_parts(41_762_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::VoteRemovalThresholdOverride` (r:1 w:0)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:1 w:1)
+	fn cancel_vote() -> Weight {
+		Weight::from_parts(45_790_000, 6687)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:0)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	fn finish_proposal() -> Weight {
+		Weight::from_parts(23_318_000, 3566)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:0)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:1 w:1)
+	fn unlock_balance() -> Weight {
+		Weight::from_parts(31_002_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
-	/// Proof: `Voting::RegisteredVoters` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Voting::Delegations` (r:1 w:0)
 	/// Storage: `Voting::Proposals` (r:1 w:1)
-	/// Proof: `Voting::Proposals` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:1 w:1)
 	/// The range of component `x` is `[1, 10000]`.
-	fn increase_proposal_time(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1303`
-		//  Estimated: `3566`
-		// Minimum execution time: 30_568_000 picoseconds.
-		Weight::from_parts(55_483_886, 3566)
-			// Standard Error: 332
-			.saturating_add(Weight::from_parts(2_744, 0).saturating_mul(x.into()))
+	fn vote(x: u32, ) -> Weight {
+		Weight::from_parts(61_204_511, 6687)
+			.saturating_add(Weight::from_parts(2_731, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Voting::Votes` (r:2 w:1)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	fn switch_vote() -> Weight {
+		Weight::from_parts(34_612_000, 3566)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:100 w:100)
+	/// Storage: `Voting::VoteCount` (r:0 w:1)
+	/// The range of component `v` is `[1, 100]`.
+	fn clean_up_proposal(v: u32, ) -> Weight {
+		Weight::from_parts(21_433_920, 6687)
+			.saturating_add(Weight::from_parts(6_904_201, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(v.into()))
+	}
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:0)
+	/// Storage: `Voting::NextSnapshotCursor` (r:1 w:1)
+	/// Storage: `Voting::Proposals` (r:10 w:0)
+	/// Storage: `Voting::TallySnapshots` (r:0 w:10)
+	/// The range of component `p` is `[0, 10]`.
+	fn snapshot_tallies(p: u32, ) -> Weight {
+		Weight::from_parts(3_498_215, 1493)
+			.saturating_add(Weight::from_parts(5_123_770, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2585).saturating_mul(p.into()))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::AmountVoters` (r:1 w:1)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
+	fn force_register_voter() -> Weight {
+		Weight::from_parts(22_640_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Voting::Paused` (r:0 w:1)
+	fn set_paused() -> Weight {
+		Weight::from_parts(7_608_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
 	/// Storage: `Voting::Proposals` (r:1 w:1)
-	/// Proof: `Voting::Proposals` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
-	/// The range of component `x` is `[1, 10000]`.
-	fn cancel_proposal(x: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1266`
-		//  Estimated: `3566`
-		// Minimum execution time: 25_731_000 picoseconds.
-		Weight::from_parts(48_152_940, 3566)
-			// Standard Error: 287
-			.saturating_add(Weight::from_parts(2_652, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	/// Storage: `Voting::ProposalsByProposer` (r:2 w:2)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn transfer_proposal_ownership() -> Weight {
+		Weight::from_parts(49_702_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Voting::Proposals` (r:50 w:50)
+	/// Storage: `Voting::VoteCount` (r:50 w:0)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::RegisteredVoters` (r:50 w:0)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	/// The range of component `n` is `[1, 50]`.
+	fn bulk_finish_proposals(n: u32, ) -> Weight {
+		Weight::from_parts(12_846_097, 3566)
+			.saturating_add(Weight::from_parts(11_927_314, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalCount` (r:1 w:1)
+	/// Storage: `Voting::StatusCounts` (r:2 w:2)
+	/// Storage: `Voting::ExpiringAt` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:1)
+	fn reopen_proposal() -> Weight {
+		Weight::from_parts(20_512_000, 3566)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Voting::Paused` (r:1 w:0)
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:0)
+	/// Storage: `Voting::Delegations` (r:1 w:0)
+	/// Storage: `Voting::Proposals` (r:1 w:0)
+	/// Storage: `Voting::Votes` (r:1 w:0)
+	/// Storage: `Voting::Commitments` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn commit_vote() -> Weight {
+		Weight::from_parts(28_104_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Voting::Paused` (r:1 w:0)
+	/// Storage: `Voting::Commitments` (r:1 w:1)
+	/// Storage: `Voting::Proposals` (r:1 w:1)
+	/// Storage: `Voting::Votes` (r:1 w:1)
+	/// Storage: `Voting::ActiveVotes` (r:1 w:1)
+	/// Storage: `Voting::VoteLimitOverride` (r:1 w:0)
+	/// Storage: `Voting::IncomingDelegations` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::VotersByProposal` (r:1 w:1)
+	/// Storage: `Voting::VoteCount` (r:1 w:1)
+	/// Storage: `Voting::CanceledVotes` (r:0 w:1)
+	fn reveal_vote() -> Weight {
+		Weight::from_parts(54_236_000, 6687)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::AmountVoters` (r:1 w:1)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::MembershipDeposits` (r:0 w:1)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
+	fn register_voter_with_deposit() -> Weight {
+		Weight::from_parts(37_502_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Voting::RegisteredVoters` (r:1 w:1)
+	/// Storage: `Voting::AmountVoters` (r:1 w:1)
+	/// Storage: `Voting::MembershipDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Voting::MaxVotersOverride` (r:1 w:0)
+	/// Storage: `Voting::CapacityWarningIssued` (r:1 w:1)
+	/// Storage: `Voting::IncomingDelegations` (r:2 w:1)
+	/// Storage: `Voting::Delegations` (r:1 w:1)
+	/// Storage: `Voting::ActiveProposalIds` (r:1 w:0)
+	/// Storage: `Voting::Votes` (r:100 w:0)
+	/// Storage: `Voting::Commitments` (r:100 w:0)
+	/// Storage: `Voting::DelegationCapOverride` (r:0 w:1)
+	/// The range of component `p` is `[0, 100]`.
+	fn unregister_voter(p: u32, ) -> Weight {
+		Weight::from_parts(43_506_000, 3593)
+			.saturating_add(Weight::from_parts(4_611_904, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(p.into()))
+	}
+	/// Storage: `Voting::DelegationCapOverride` (r:0 w:1)
+	fn set_delegation_cap() -> Weight {
+		Weight::from_parts(10_245_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
\ No newline at end of file
@@ -232,14 +232,84 @@ parameter_types! {
 	pub const MaxVoters: u32 = 100;
 	pub const VoteRemovalThreshold: u32 = 20;
 	pub const VoteLimit: u32 = 5;
+	pub const MinVoteAmount: u32 = 1;
+	pub const MaxBatchSize: u32 = 50;
+	pub const MaxProposalVotes: u32 = 10_000;
+	pub const MaxVotersPerProposal: u32 = 100;
+	pub const Quorum: u32 = 3;
+	pub const ProposalCooldown: BlockNumber = 10;
+	pub const ProposalDeposit: Balance = 10 * EXISTENTIAL_DEPOSIT;
+	pub const MaxProposalDuration: BlockNumber = 30 * DAYS;
+	pub const UseQuadraticTally: bool = false;
+	pub const SnapshotInterval: BlockNumber = HOURS;
+	pub const MaxSnapshotsPerBlock: u32 = 10;
+	pub const ConvictionPeriod: BlockNumber = DAYS;
+	pub const PostResultLock: BlockNumber = HOURS;
+	pub const RevealPeriod: BlockNumber = HOURS;
+	pub const CommitmentDeposit: Balance = EXISTENTIAL_DEPOSIT;
+	pub const MaxExpiriesPerBlock: u32 = 20;
+	pub const ProposerCanVote: bool = true;
+	pub const MembershipDeposit: Balance = 5 * EXISTENTIAL_DEPOSIT;
+	pub const MaxDelegationsPerAccount: u32 = 20;
+	pub const MinProposalDuration: BlockNumber = 10 * MINUTES;
+	pub const CapacityWarnThreshold: u32 = 10;
+	pub const MaxProposals: u32 = 100;
+	pub const MaxProposalsPerProposer: u32 = 20;
+	pub const MaxVotesPerVoter: u32 = 50;
+	pub const MaxCallSize: u32 = 1024;
+	pub const MaxTags: u32 = 5;
+	pub const MaxTagLen: u32 = 32;
+	pub const VotingPalletId: frame_support::PalletId = frame_support::PalletId(*b"py/votin");
+}
+
+/// Dispatches the calls of passed proposals as root.
+pub struct VotingEnactmentOrigin;
+
+impl frame_support::traits::Get<RuntimeOrigin> for VotingEnactmentOrigin {
+	fn get() -> RuntimeOrigin {
+		frame_system::RawOrigin::Root.into()
+	}
 }
 
 impl pallet_voting::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type PalletId = VotingPalletId;
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+	type RuntimeCall = RuntimeCall;
+	type EnactmentOrigin = VotingEnactmentOrigin;
 	type MaxVoters = MaxVoters;
 	type VoteLimit = VoteLimit;
+	type MinVoteAmount = MinVoteAmount;
 	type VoteRemovalThreshold = VoteRemovalThreshold;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxProposalVotes = MaxProposalVotes;
+	type MaxVotersPerProposal = MaxVotersPerProposal;
+	type Quorum = Quorum;
+	type ProposalCooldown = ProposalCooldown;
+	type ProposalDeposit = ProposalDeposit;
+	type MaxProposalDuration = MaxProposalDuration;
+	type MaxProposals = MaxProposals;
+	type MaxProposalsPerProposer = MaxProposalsPerProposer;
+	type MaxVotesPerVoter = MaxVotesPerVoter;
+	type MaxCallSize = MaxCallSize;
+	type MaxTags = MaxTags;
+	type MaxTagLen = MaxTagLen;
+	type UseQuadraticTally = UseQuadraticTally;
+	type TieBreaker = pallet_voting::AlwaysTie;
+	type SnapshotInterval = SnapshotInterval;
+	type MaxSnapshotsPerBlock = MaxSnapshotsPerBlock;
+	type ConvictionPeriod = ConvictionPeriod;
+	type PostResultLock = PostResultLock;
+	type RevealPeriod = RevealPeriod;
+	type CommitmentDeposit = CommitmentDeposit;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type ProposerCanVote = ProposerCanVote;
+	type MembershipDeposit = MembershipDeposit;
+	type MaxDelegationsPerAccount = MaxDelegationsPerAccount;
+	type MinProposalDuration = MinProposalDuration;
+	type CapacityWarnThreshold = CapacityWarnThreshold;
+	type SlashHandler = ();
 	type WeightInfo = ();
 }
 
@@ -248,7 +318,7 @@ pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = ConstU32<50>;
-	type MaxReserves = ();
+	type MaxReserves = ConstU32<50>;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -335,8 +405,12 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations executed on runtime upgrade.
+pub type Migrations = (pallet_voting::migrations::v1::MigrateToV1<Runtime>,);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -522,6 +596,14 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_voting_runtime_api::VotingApi<Block, BlockNumber> for Runtime {
+		fn proposal_state(
+			id: pallet_voting::ProposalId,
+		) -> Option<pallet_voting::ProposalStateInfo<BlockNumber>> {
+			Voting::proposal_state(id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (